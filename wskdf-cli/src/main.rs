@@ -1,6 +1,7 @@
 use std::{
     io::{Read, Write},
     path::PathBuf,
+    sync::atomic::{AtomicU64, Ordering},
 };
 
 use anyhow::{Context, ensure};
@@ -15,8 +16,30 @@ const DEFAULT_MEM_LIMIT_KBYTES: u32 = 4096 * 1024;
 const STDIN_HELP: &str = "Use - for stdin";
 const STDOUT_HELP: &str = "Use - for stdout";
 
-#[derive(Clone, clap::Args, serde::Serialize)]
+#[derive(Clone, Copy, PartialEq, Eq, clap::ValueEnum, serde::Serialize, serde::Deserialize)]
+enum SecurityLevelArg {
+    Interactive,
+    Moderate,
+    Sensitive,
+}
+
+impl From<SecurityLevelArg> for wskdf_core::SecurityLevel {
+    fn from(level: SecurityLevelArg) -> Self {
+        match level {
+            SecurityLevelArg::Interactive => wskdf_core::SecurityLevel::Interactive,
+            SecurityLevelArg::Moderate => wskdf_core::SecurityLevel::Moderate,
+            SecurityLevelArg::Sensitive => wskdf_core::SecurityLevel::Sensitive,
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, clap::Args, serde::Serialize, serde::Deserialize)]
 struct KdfParams {
+    /// A named cost preset, used instead of --ops-limit/--mem-limit-kbytes; it cannot be
+    /// given together with either of them
+    #[arg(long, value_enum, conflicts_with_all = ["ops_limit", "mem_limit_kbytes"])]
+    security_level: Option<SecurityLevelArg>,
+
     #[arg(long, default_value_t = DEFAULT_OPS_LIMIT)]
     ops_limit: u32,
 
@@ -24,6 +47,43 @@ struct KdfParams {
     mem_limit_kbytes: u32,
 }
 
+impl KdfParams {
+    /// Resolves to the concrete Argon2 cost: `--security-level`'s preset if given,
+    /// otherwise the raw `--ops-limit`/`--mem-limit-kbytes` pair.
+    fn to_params(&self) -> wskdf_core::Params {
+        match self.security_level {
+            Some(level) => wskdf_core::SecurityLevel::from(level).to_params(),
+            None => wskdf_core::Params::new(self.ops_limit, self.mem_limit_kbytes),
+        }
+    }
+
+    /// Resolves `--n-bits` if given, otherwise falls back to the chosen `--security-level`'s
+    /// recommended value.
+    fn resolve_n_bits(&self, n_bits: Option<u8>) -> anyhow::Result<u8> {
+        match n_bits {
+            Some(n_bits) => Ok(n_bits),
+            None => {
+                let level = self
+                    .security_level
+                    .context("--n-bits is required unless --security-level is given")?;
+                Ok(wskdf_core::SecurityLevel::from(level)
+                    .recommended_n_bits()
+                    .expect("named security levels always have a recommended n_bits"))
+            }
+        }
+    }
+}
+
+/// How preimage/key/salt files are read and written
+#[derive(Clone, Copy, Default, clap::ValueEnum)]
+enum Encoding {
+    /// Plain hex, e.g. "000102...0f"
+    #[default]
+    Hex,
+    /// A self-validating BIP39-style word list, easier to transcribe by hand
+    Mnemonic,
+}
+
 #[derive(Clone, clap::Parser)]
 #[command(name = "wskdf", about = "Weak, Slow, Key Derivation Function", long_about = None)]
 struct Cli {
@@ -33,10 +93,12 @@ struct Cli {
 
 #[derive(Clone, clap::Subcommand)]
 enum Commands {
-    /// Outputs a random preimage and the derived key encoded as hex to two files
+    /// Outputs a random preimage and the derived key (hex or mnemonic) to two files
     OutputRandomKey {
+        /// Defaults to the --security-level's recommended value; required if
+        /// --security-level is not given
         #[arg(short, long)]
-        n_bits: u8,
+        n_bits: Option<u8>,
 
         #[arg(long, help = STDOUT_HELP)]
         preimage_output: PathBuf,
@@ -52,6 +114,10 @@ enum Commands {
 
         #[clap(flatten)]
         kdf_params: KdfParams,
+
+        /// Encoding used for the salt input and the preimage/key outputs
+        #[arg(long, value_enum, default_value_t = Encoding::Hex)]
+        encoding: Encoding,
     },
     /// Derives a key from a preimage
     DeriveKey {
@@ -66,6 +132,10 @@ enum Commands {
 
         #[clap(flatten)]
         kdf_params: KdfParams,
+
+        /// Encoding used for the preimage/salt inputs and the key output
+        #[arg(long, value_enum, default_value_t = Encoding::Hex)]
+        encoding: Encoding,
     },
     /// Brute force finds the preimage/key pair using the external command.
     /// The command should receive one the hex encoded derived key on the stdin.
@@ -81,8 +151,10 @@ enum Commands {
         #[arg(long, help = STDOUT_HELP)]
         key_output: Option<PathBuf>,
 
+        /// Defaults to the --security-level's recommended value; required if
+        /// --security-level is not given
         #[arg(short, long)]
-        n_bits: u8,
+        n_bits: Option<u8>,
 
         /// Number of threads. If in doubt, run the benchmark first with a smaller number of threads
         #[arg(short, long)]
@@ -91,6 +163,33 @@ enum Commands {
         #[arg(long, help = STDIN_HELP)]
         salt_input: PathBuf,
 
+        /// Seed the walk start deterministically instead of drawing it from the OS RNG, so
+        /// the exact same search can be reproduced or shared
+        #[arg(long)]
+        seed: Option<u64>,
+
+        /// Periodically records completed blocks of the search space here so an interrupted
+        /// run can be resumed with --resume instead of restarting from scratch. Not supported
+        /// together with --coordinator/--worker
+        #[arg(long)]
+        checkpoint: Option<PathBuf>,
+
+        /// Resume a previous run from --checkpoint instead of starting a fresh search
+        #[arg(long)]
+        resume: bool,
+
+        /// Run as the distributed search coordinator, listening on this address and sharding
+        /// the search space out to connecting --worker processes instead of searching
+        /// locally. Requires --seed, so every worker walks the same sequence
+        #[arg(long, conflicts_with = "worker")]
+        coordinator: Option<std::net::SocketAddr>,
+
+        /// Run as a distributed search worker, connecting to a --coordinator at this address
+        /// for its share of the search space instead of searching the full space locally.
+        /// Requires --seed, so every worker walks the same sequence
+        #[arg(long, conflicts_with = "coordinator")]
+        worker: Option<std::net::SocketAddr>,
+
         #[clap(flatten)]
         kdf_params: KdfParams,
     },
@@ -107,9 +206,13 @@ enum Commands {
 
         #[clap(flatten)]
         kdf_params: KdfParams,
+
+        /// Encoding used for the key/preimage/salt inputs
+        #[arg(long, value_enum, default_value_t = Encoding::Hex)]
+        encoding: Encoding,
     },
     Benchmark {
-        /// Iterations per thread
+        /// Iterations per thread in each measurement batch
         #[arg(short, long)]
         iterations: usize,
 
@@ -117,6 +220,19 @@ enum Commands {
         #[arg(short, long)]
         threads: usize,
 
+        /// Initial batches run and discarded before sample collection starts
+        #[arg(long, default_value_t = 2)]
+        warmup_batches: usize,
+
+        /// Wall-time budget for the adaptive sampling loop, e.g. "30s" or "2min"
+        #[arg(long, default_value_t = 30.0, value_parser = parse_duration)]
+        bench_max_secs: f64,
+
+        /// Stop sampling once the relative standard error (stddev / mean / sqrt(n)) drops
+        /// to this fraction
+        #[arg(long, default_value_t = 0.02)]
+        target_relative_stderr: f64,
+
         #[clap(flatten)]
         kdf_params: KdfParams,
     },
@@ -137,6 +253,28 @@ enum Commands {
         /// Maximum bit length to calculate
         #[arg(long, default_value_t = 32)]
         max_bits: u8,
+
+        /// Percentiles to report, as a comma-separated list of fractions (e.g. "0.5,0.99")
+        #[arg(long, value_delimiter = ',', default_values_t = vec![0.50, 0.90, 0.95, 0.99])]
+        percentiles: Vec<f64>,
+
+        /// How many leading magnitude components to show for each duration (e.g. 3 shows
+        /// "5d 17h 3min" instead of "5d 17h")
+        #[arg(long, default_value_t = 2)]
+        precision: usize,
+    },
+    /// Auto-tunes ops_limit for a target single-derivation time at a fixed memory limit
+    CalibrateParams {
+        /// Desired single-derivation time, e.g. "1.5s" or "500ms"
+        #[arg(long, value_parser = parse_duration)]
+        target_secs: f64,
+
+        /// Number of threads to use while measuring
+        #[arg(short, long)]
+        threads: usize,
+
+        #[arg(long, default_value_t = DEFAULT_MEM_LIMIT_KBYTES)]
+        mem_limit_kbytes: u32,
     },
 }
 
@@ -156,6 +294,7 @@ fn main() -> anyhow::Result<()> {
             params_output,
             salt_input,
             kdf_params,
+            encoding,
         } => {
             ensure_file_does_not_exists(&preimage_output, "preimage output file already exists")?;
             ensure_file_does_not_exists(&key_output, "key output file already exists")?;
@@ -163,19 +302,15 @@ fn main() -> anyhow::Result<()> {
                 ensure_file_does_not_exists(params_output, "params output file already exists")?;
             }
             let salt = read_file(&salt_input)?;
-            let salt = parse_salt(&salt)?;
+            let salt = parse_salt(&salt, encoding)?;
+            let n_bits = kdf_params.resolve_n_bits(n_bits)?;
             let preimage = wskdf_core::gen_rand_preimage(n_bits)?;
-            let preimage_hex = hex::encode(preimage);
-            let key = wskdf_core::wskdf_derive_key(
-                &preimage,
-                &salt,
-                kdf_params.ops_limit,
-                kdf_params.mem_limit_kbytes,
-            )
-            .context("derive key failed")?;
-            let key_hex = hex::encode(key);
-            write_file(&preimage_output, &preimage_hex)?;
-            write_file(&key_output, &key_hex)?;
+            let preimage_encoded = encode_preimage(&preimage, encoding);
+            let key = wskdf_core::wskdf_derive_key(&preimage, &salt, &kdf_params.to_params())
+                .context("derive key failed")?;
+            let key_encoded = encode_key(&key, encoding);
+            write_file(&preimage_output, &preimage_encoded)?;
+            write_file(&key_output, &key_encoded)?;
             if let Some(params_output) = &params_output {
                 write_file(
                     params_output,
@@ -188,21 +323,17 @@ fn main() -> anyhow::Result<()> {
             salt_input,
             kdf_params,
             key_output,
+            encoding,
         } => {
             ensure_file_does_not_exists(&key_output, "key output file already exists")?;
             let salt = read_file(&salt_input)?;
-            let salt = parse_salt(&salt)?;
+            let salt = parse_salt(&salt, encoding)?;
             let preimage = read_file(&preimage_input)?;
-            let preimage = parse_preimage(&preimage)?;
-            let key = wskdf_core::wskdf_derive_key(
-                &preimage,
-                &salt,
-                kdf_params.ops_limit,
-                kdf_params.mem_limit_kbytes,
-            )
-            .context("derive key failed")?;
-            let key_hex = hex::encode(key);
-            write_file(&key_output, &key_hex)?;
+            let preimage = parse_preimage(&preimage, encoding)?;
+            let key = wskdf_core::wskdf_derive_key(&preimage, &salt, &kdf_params.to_params())
+                .context("derive key failed")?;
+            let key_encoded = encode_key(&key, encoding);
+            write_file(&key_output, &key_encoded)?;
         }
         Commands::FindKey {
             command,
@@ -211,15 +342,90 @@ fn main() -> anyhow::Result<()> {
             n_bits,
             threads,
             salt_input,
+            seed,
+            checkpoint,
+            resume,
+            coordinator,
+            worker,
             kdf_params,
         } => {
             ensure!(threads > 0, "threads must be > 0");
+            ensure!(
+                !resume || checkpoint.is_some(),
+                "--resume requires --checkpoint"
+            );
+            ensure!(
+                coordinator.is_none() || seed.is_some(),
+                "--coordinator requires --seed, so every worker walks the same sequence"
+            );
+            ensure!(
+                worker.is_none() || seed.is_some(),
+                "--worker requires --seed, so every worker walks the same sequence"
+            );
+            ensure!(
+                (coordinator.is_none() && worker.is_none()) || checkpoint.is_none(),
+                "--checkpoint/--resume are not supported together with --coordinator/--worker"
+            );
             ensure_file_does_not_exists(&preimage_output, "preimage output file already exists")?;
             if let Some(key_output) = &key_output {
                 ensure_file_does_not_exists(key_output, "key output file already exists")?;
             }
             let salt = read_file(&salt_input)?;
-            let salt = parse_salt(&salt)?;
+            let salt = parse_salt(&salt, Encoding::Hex)?;
+            let n_bits = kdf_params.resolve_n_bits(n_bits)?;
+            let space = 1u64 << (n_bits - 1); // 2^(n-1)
+            let num_blocks = space.div_ceil(CHECKPOINT_BLOCK_SIZE);
+
+            if let Some(coordinator_addr) = coordinator {
+                return run_coordinator(
+                    coordinator_addr,
+                    n_bits,
+                    &salt,
+                    &kdf_params,
+                    seed.expect("checked above"),
+                    space,
+                    num_blocks,
+                    &preimage_output,
+                    &key_output,
+                );
+            }
+            if let Some(worker_addr) = worker {
+                return run_worker(
+                    worker_addr,
+                    n_bits,
+                    &salt,
+                    &kdf_params,
+                    seed.expect("checked above"),
+                    threads,
+                    &preimage_output,
+                    &key_output,
+                    &command,
+                );
+            }
+
+            let bitmap = if resume {
+                let checkpoint_path = checkpoint.as_ref().expect("checked above");
+                let loaded = load_checkpoint(checkpoint_path)?;
+                ensure!(
+                    loaded.n_bits == n_bits
+                        && loaded.salt_hex == hex::encode(salt)
+                        && loaded.kdf_params == kdf_params,
+                    "checkpoint at {} was recorded with different n_bits/salt/kdf_params",
+                    checkpoint_path.display()
+                );
+                eprintln!(
+                    "Resuming from checkpoint at {} ({} of {num_blocks} blocks already done)",
+                    checkpoint_path.display(),
+                    loaded.bitmap.count_set()
+                );
+                loaded.bitmap
+            } else {
+                if let Some(checkpoint) = &checkpoint {
+                    ensure_file_does_not_exists(checkpoint, "checkpoint file already exists")?;
+                }
+                BlockBitmap::default()
+            };
+            let mut bitmap = bitmap;
 
             eprintln!("Using {threads} rayon threads");
             // Build a dedicated rayon pool with the requested number of threads so that we
@@ -228,13 +434,18 @@ fn main() -> anyhow::Result<()> {
                 .num_threads(threads)
                 .build()
                 .context("failed to build rayon pool")?;
+            eprintln!("Calibrating derivation time on this machine...");
+            let est_time_per_trial = calibrate_derivation_time(&pool, &salt, &kdf_params, threads)?;
+            eprintln!(
+                "Measured {:.3}s per derivation ({:.2} derivations/sec per thread)",
+                est_time_per_trial,
+                1.0 / est_time_per_trial
+            );
+
             eprintln!("Starting parallel search");
 
             // Estimate search completion times
-            let space = 1u64 << (n_bits - 1); // 2^(n-1)
             let expected_trials = space as f64 / (2.0 * threads as f64);
-            // Rough estimate based on typical KDF performance - this could be calibrated
-            let est_time_per_trial = 0.1; // seconds - rough placeholder
             let expected_time = expected_trials * est_time_per_trial;
 
             eprintln!("\nTime estimates for full search:");
@@ -254,34 +465,35 @@ fn main() -> anyhow::Result<()> {
             eprintln!();
 
             let now = std::time::Instant::now();
-            let start = {
-                let mut rng = rand::rngs::ThreadRng::default();
-                rand::Rng::random_range(&mut rng, 0..space)
-            };
-            let found_preimage = pool.install(|| {
-                (0..space).into_par_iter().find_map_any(|idx| {
-                    // deterministic walk starting at `start`
-                    let preimage_bytes = index_to_preimage(idx, start, n_bits);
-                    let preimage_hex = hex::encode(preimage_bytes);
-                    eprintln!("Deriving key for {preimage_hex}");
-                    let derived_key = wskdf_core::wskdf_derive_key(
-                        &preimage_bytes,
-                        &salt,
-                        kdf_params.ops_limit,
-                        kdf_params.mem_limit_kbytes,
-                    )
-                    .expect("derive key to complete");
-                    let key_hex = hex::encode(derived_key);
-                    if exec_and_send_to_stdin(key_hex.as_bytes(), command.clone())
-                        .map(|s| s.success())
-                        .unwrap_or(false)
-                    {
-                        Some((preimage_hex, key_hex))
-                    } else {
-                        None
-                    }
-                })
-            });
+            let start = compute_walk_start(seed, space);
+
+            // Blocks are visited in order and each is fully parallelized over `pool` via
+            // `search_subrange`; only a completed block gets marked and flushed, so an
+            // interrupted run resumes by skipping the blocks already recorded in `bitmap`.
+            let mut found_preimage = None;
+            for block in 0..num_blocks {
+                if bitmap.is_set(block) {
+                    continue;
+                }
+                let block_start = block * CHECKPOINT_BLOCK_SIZE;
+                let block_end = (block_start + CHECKPOINT_BLOCK_SIZE).min(space);
+                found_preimage = search_subrange(
+                    &pool,
+                    block_start..block_end,
+                    start,
+                    n_bits,
+                    &salt,
+                    &kdf_params,
+                    &command,
+                );
+                if found_preimage.is_some() {
+                    break;
+                }
+                bitmap.set(block);
+                if let Some(checkpoint) = &checkpoint {
+                    save_checkpoint(checkpoint, n_bits, &salt, &kdf_params, &bitmap)?;
+                }
+            }
             match found_preimage {
                 Some((preimage_hex, derived_key_hex)) => {
                     eprintln!("Found key in {}", pretty(now.elapsed().as_secs_f64()));
@@ -304,29 +516,33 @@ fn main() -> anyhow::Result<()> {
             preimage_input,
             salt_input,
             kdf_params,
+            encoding,
         } => {
             let key = read_file(&key_input)?;
-            let key = parse_key(&key)?;
+            let key = parse_key(&key, encoding)?;
             let preimage = read_file(&preimage_input)?;
-            let preimage = parse_preimage(&preimage)?;
+            let preimage = parse_preimage(&preimage, encoding)?;
             let salt = read_file(&salt_input)?;
-            let salt = parse_salt(&salt)?;
-            let derived_key = wskdf_core::wskdf_derive_key(
-                &preimage,
-                &salt,
-                kdf_params.ops_limit,
-                kdf_params.mem_limit_kbytes,
-            )
-            .context("derive key failed")?;
+            let salt = parse_salt(&salt, encoding)?;
+            let derived_key = wskdf_core::wskdf_derive_key(&preimage, &salt, &kdf_params.to_params())
+                .context("derive key failed")?;
             anyhow::ensure!(derived_key == key, "derived key doesn't match");
         }
         Commands::Benchmark {
             iterations,
             threads,
+            warmup_batches,
+            bench_max_secs,
+            target_relative_stderr,
             kdf_params,
         } => {
             ensure!(iterations > 0, "iterations must be > 0");
             ensure!(threads > 0, "threads must be > 0");
+            ensure!(bench_max_secs > 0.0, "bench-max-secs must be > 0");
+            ensure!(
+                target_relative_stderr > 0.0,
+                "target-relative-stderr must be > 0"
+            );
             eprintln!("Using {threads} threads for benchmark");
 
             // Build a dedicated rayon pool with the requested number of threads
@@ -340,63 +556,72 @@ fn main() -> anyhow::Result<()> {
             let salt = [0u8; SALT_SIZE]; // Fixed salt for consistent benchmarking
 
             eprintln!(
-                "Starting benchmark with {iterations} iterations across {threads} threads..."
+                "Benchmarking {iterations} iterations/thread across {threads} threads (warmup={warmup_batches}, max={bench_max_secs}s, target stderr={target_relative_stderr})..."
+            );
+            let stats = run_adaptive_benchmark(
+                &pool,
+                &preimage,
+                &salt,
+                &kdf_params,
+                threads,
+                iterations,
+                warmup_batches,
+                bench_max_secs,
+                target_relative_stderr,
             );
-            let start = std::time::Instant::now();
-
-            let total_iterations = iterations * threads;
-            // Execute the benchmark in parallel using the thread pool
-            pool.install(|| {
-                (0..total_iterations).into_par_iter().for_each(|_i| {
-                    let _key = wskdf_core::wskdf_derive_key(
-                        &preimage,
-                        &salt,
-                        kdf_params.ops_limit,
-                        kdf_params.mem_limit_kbytes,
-                    )
-                    .expect("key derivation to work");
-                });
-            });
-
-            let f64_iterations = iterations as f64;
-            let f64_total_iterations = total_iterations as f64;
-            let f64_duration_secs = start.elapsed().as_secs_f64();
-            let avg_time = f64_duration_secs / f64_total_iterations;
-            let derivations_per_second = f64_total_iterations / f64_duration_secs;
-            let thread_avg_time = f64_duration_secs / f64_iterations;
-            let thread_derivations_per_second = f64_iterations / f64_duration_secs;
 
             eprintln!("\nBenchmark results:");
             eprintln!("Threads: {threads}");
-            eprintln!("Total time: {f64_duration_secs:.2?}s");
-            eprintln!("Total iterations: {total_iterations}");
             eprintln!(
-                "Global average time per derivation: {avg_time:.2?}ms",
-                avg_time = avg_time * 1000.0
+                "Samples used: {} (discarded {} as outliers)",
+                stats.samples_used, stats.samples_discarded
+            );
+            eprintln!("Min time per derivation: {}", pretty(stats.min_secs));
+            eprintln!("Median time per derivation: {}", pretty(stats.median_secs));
+            eprintln!("Mean time per derivation: {}", pretty(stats.mean_secs));
+            eprintln!(
+                "Stddev of time per derivation: {:.3}s",
+                stats.stddev_secs
+            );
+            eprintln!(
+                "Derivations per second (single thread): {:.3}",
+                stats.derivations_per_second
             );
-            eprintln!("Global derivations per second: {derivations_per_second:.2?}");
-            eprintln!("Thread average time per derivation: {thread_avg_time:.2?}s");
-            eprintln!("Thread derivations per second: {thread_derivations_per_second:.2?}");
 
             eprintln!("\nEstimated time to brute-force one preimage/key pair:");
             eprintln!("Note: This benchmark uses {threads} threads with systematic search");
             eprintln!("For comparison with random search percentiles, see README table");
             eprintln!();
             eprintln!(
-                "{:>4} │ {:>18} │ {:>18}",
-                "bits", "systematic (worst)", "systematic (expected)"
+                "{:>4} │ {:>18} │ {:>18} │ {:>18}",
+                "bits", "systematic (worst)", "systematic (expected)", "monte carlo (p99)"
+            );
+            eprintln!(
+                "{:->4}-┼-{:->18}-┼-{:->18}-┼-{:->18}",
+                "", "", "", ""
             );
-            eprintln!("{:->4}-┼-{:->18}-┼-{:->18}", "", "", "");
 
+            const MONTE_CARLO_SAMPLES: usize = 1_000;
             for bits in 1u8..=32 {
                 let space = calculate_search_space(bits);
                 let (systematic_expected_secs, systematic_worst_secs) =
-                    calculate_systematic_times(space, threads, thread_avg_time);
+                    calculate_systematic_times(space, threads, stats.mean_secs);
+                let monte_carlo = simulate_search(
+                    bits,
+                    threads,
+                    Dist::LogNormal {
+                        mean: stats.mean_secs,
+                        stddev: stats.stddev_secs,
+                    },
+                    Strategy::Systematic,
+                    MONTE_CARLO_SAMPLES,
+                );
 
                 let systematic_worst_human = pretty(systematic_worst_secs);
                 let systematic_expected_human = pretty(systematic_expected_secs);
+                let monte_carlo_human = pretty(monte_carlo.p99());
                 eprintln!(
-                    "{bits:>4} │ {systematic_worst_human:>18} │ {systematic_expected_human:>18}"
+                    "{bits:>4} │ {systematic_worst_human:>18} │ {systematic_expected_human:>18} │ {monte_carlo_human:>18}"
                 );
             }
 
@@ -405,7 +630,9 @@ fn main() -> anyhow::Result<()> {
             eprintln!(
                 "• Expected case: Threads find target halfway through their partitions on average"
             );
-            eprintln!("• No variance: Deterministic partitioning means predictable bounds");
+            eprintln!(
+                "• Monte carlo (p99): simulated completion time drawing per-derivation time from the measured mean/stddev"
+            );
             eprintln!("\nFor random search with percentiles, see the README table comparing");
             eprintln!("systematic (16 threads) vs random search (2048 threads)");
         }
@@ -413,33 +640,55 @@ fn main() -> anyhow::Result<()> {
             avg_time_secs,
             threads,
             max_bits,
+            percentiles,
+            precision,
         } => {
+            ensure!(!percentiles.is_empty(), "percentiles must not be empty");
+            for &p in &percentiles {
+                ensure!(
+                    (0.0..1.0).contains(&p),
+                    "percentile {p} must be in [0.0, 1.0)"
+                );
+            }
+            ensure!(precision >= 1, "precision must be >= 1");
+
             eprintln!("Time estimation for different bit lengths:");
-            eprintln!("Average derivation time: {avg_time_secs:.2}s");
+            eprintln!("Average derivation time: {}", pretty(avg_time_secs));
             eprintln!("Thread count: {threads}");
             eprintln!();
 
+            let percentile_header = percentiles
+                .iter()
+                .map(|p| format!("p{:.0}", p * 100.0))
+                .collect::<Vec<_>>()
+                .join(" │ ");
             eprintln!(
-                "bits │ systematic-{threads}t │ systematic-{threads}t │ random-{threads}t │ random-{threads}t │ random-{threads}t"
-            );
-            eprintln!(
-                "     │ (expected)     │ (worst case)   │ (expected)│ (99th %)  │ (99.9th %)"
-            );
-            eprintln!(
-                "-----┼----------------┼----------------┼-----------┼-----------┼------------"
+                "bits │ systematic (expected) │ systematic (worst) │ {percentile_header} (systematic) │ random (expected) │ {percentile_header} (random)"
             );
 
             for bits in 1u8..=max_bits {
-                let result = calculate_estimation_for_bits(bits, threads, avg_time_secs);
-
-                let systematic_expected_human = pretty(result.systematic_expected_secs);
-                let systematic_worst_human = pretty(result.systematic_worst_secs);
-                let random_expected_human = pretty(result.random_expected_secs);
-                let random_99th_human = pretty(result.random_99th_percentile_secs);
-                let random_999th_human = pretty(result.random_999th_percentile_secs);
+                let result = calculate_estimation_for_bits(bits, threads, avg_time_secs, &percentiles);
+
+                let (_, systematic_worst_secs) =
+                    calculate_systematic_times(calculate_search_space(bits), threads, avg_time_secs);
+                let systematic_expected_human = pretty_with_precision(result.systematic_expected_secs, precision);
+                let systematic_worst_human = pretty_with_precision(systematic_worst_secs, precision);
+                let random_expected_human = pretty_with_precision(result.random_expected_secs, precision);
+                let systematic_percentile_human = result
+                    .systematic_percentiles
+                    .iter()
+                    .map(|&(_, secs)| pretty_with_precision(secs, precision))
+                    .collect::<Vec<_>>()
+                    .join(" │ ");
+                let random_percentile_human = result
+                    .random_percentiles
+                    .iter()
+                    .map(|&(_, secs)| pretty_with_precision(secs, precision))
+                    .collect::<Vec<_>>()
+                    .join(" │ ");
 
                 eprintln!(
-                    "{bits:>4} │ {systematic_expected_human:>14} │ {systematic_worst_human:>14} │ {random_expected_human:>9} │ {random_99th_human:>9} │ {random_999th_human:>10}"
+                    "{bits:>4} │ {systematic_expected_human:>18} │ {systematic_worst_human:>15} │ {systematic_percentile_human} │ {random_expected_human:>14} │ {random_percentile_human}"
                 );
             }
 
@@ -454,8 +703,9 @@ fn main() -> anyhow::Result<()> {
             eprintln!(
                 "• Random (expected): {threads} threads with expected 2^(n-1) / {threads} trials per thread"
             );
-            eprintln!("• Random (99th %): 99% chance completion is faster than this");
-            eprintln!("• Random (99.9th %): 99.9% chance completion is faster than this");
+            eprintln!(
+                "• pN: chance completion is faster than this (systematic: uniform fraction of worst-case; random: exponential tail of expected)"
+            );
         }
         Commands::GenerateSalt { output } => {
             ensure_file_does_not_exists(&output, "output file already exists")?;
@@ -464,6 +714,31 @@ fn main() -> anyhow::Result<()> {
             let salt_hex = hex::encode(salt);
             write_file(&output, &salt_hex)?;
         }
+        Commands::CalibrateParams {
+            target_secs,
+            threads,
+            mem_limit_kbytes,
+        } => {
+            ensure!(target_secs > 0.0, "target_secs must be > 0");
+            ensure!(threads > 0, "threads must be > 0");
+
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .context("failed to build rayon pool")?;
+            let salt = [0u8; SALT_SIZE]; // fixed salt, consistent with Benchmark
+            let preimage = wskdf_core::gen_rand_preimage(32)?;
+
+            let ops_limit =
+                calibrate_ops_limit(&pool, &preimage, &salt, mem_limit_kbytes, target_secs)?;
+
+            let kdf_params = KdfParams {
+                security_level: None,
+                ops_limit,
+                mem_limit_kbytes,
+            };
+            println!("{}", serde_json::to_string_pretty(&kdf_params)?);
+        }
     };
     Ok(())
 }
@@ -495,28 +770,52 @@ fn write_file(path: &std::path::Path, content: &str) -> anyhow::Result<()> {
     }
 }
 
-fn parse_salt(salt: &str) -> anyhow::Result<[u8; SALT_SIZE]> {
-    let result = hex::decode(salt)
-        .context("salt isn't valid hex")?
-        .try_into()
-        .map_err(|k| anyhow::anyhow!("salt doesn't fit in [u8; SALT_SIZE]: {k:?}"))?;
-    Ok(result)
+fn parse_salt(salt: &str, encoding: Encoding) -> anyhow::Result<[u8; SALT_SIZE]> {
+    match encoding {
+        Encoding::Hex => hex::decode(salt.trim())
+            .context("salt isn't valid hex")?
+            .try_into()
+            .map_err(|k: Vec<u8>| anyhow::anyhow!("salt doesn't fit in [u8; SALT_SIZE]: {k:?}")),
+        Encoding::Mnemonic => wskdf_core::decode_mnemonic(salt.trim(), SALT_SIZE)?
+            .try_into()
+            .map_err(|k: Vec<u8>| anyhow::anyhow!("salt doesn't fit in [u8; SALT_SIZE]: {k:?}")),
+    }
+}
+
+fn parse_preimage(preimage: &str, encoding: Encoding) -> anyhow::Result<[u8; PREIMAGE_SIZE]> {
+    match encoding {
+        Encoding::Hex => hex::decode(preimage.trim())
+            .context("preimage isn't valid hex")?
+            .try_into()
+            .map_err(|k: Vec<u8>| {
+                anyhow::anyhow!("preimage doesn't fit in [u8; PREIMAGE_SIZE]: {k:?}")
+            }),
+        Encoding::Mnemonic => wskdf_core::decode_preimage_mnemonic(preimage.trim()),
+    }
 }
 
-fn parse_preimage(preimage: &str) -> anyhow::Result<[u8; PREIMAGE_SIZE]> {
-    let preimage = hex::decode(preimage)
-        .context("preimage isn't valid hex")?
-        .try_into()
-        .map_err(|k| anyhow::anyhow!("preimage doesn't fit in [u8; PREIMAGE_SIZE]: {k:?}"))?;
-    Ok(preimage)
+fn parse_key(key: &str, encoding: Encoding) -> anyhow::Result<[u8; KEY_SIZE]> {
+    match encoding {
+        Encoding::Hex => hex::decode(key.trim())
+            .context("key isn't valid hex")?
+            .try_into()
+            .map_err(|k: Vec<u8>| anyhow::anyhow!("key doesn't fit in [u8; KEY_SIZE]: {k:?}")),
+        Encoding::Mnemonic => wskdf_core::decode_key_mnemonic(key.trim()),
+    }
 }
 
-fn parse_key(key: &str) -> anyhow::Result<[u8; KEY_SIZE]> {
-    let key = hex::decode(key)
-        .context("key isn't valid hex")?
-        .try_into()
-        .map_err(|k| anyhow::anyhow!("key doesn't fit in [u8; KEY_SIZE]: {k:?}"))?;
-    Ok(key)
+fn encode_preimage(preimage: &[u8; PREIMAGE_SIZE], encoding: Encoding) -> String {
+    match encoding {
+        Encoding::Hex => hex::encode(preimage),
+        Encoding::Mnemonic => wskdf_core::encode_preimage_mnemonic(preimage),
+    }
+}
+
+fn encode_key(key: &[u8; KEY_SIZE], encoding: Encoding) -> String {
+    match encoding {
+        Encoding::Hex => hex::encode(key),
+        Encoding::Mnemonic => wskdf_core::encode_key_mnemonic(key),
+    }
 }
 
 /// Return the `i`-th candidate in the n-bit space, interpreted as
@@ -530,6 +829,418 @@ fn index_to_preimage(i: u64, start: u64, n_bits: u8) -> [u8; 8] {
     value.to_be_bytes()
 }
 
+/// Picks the `index_to_preimage` walk's starting offset: deterministically from `seed` via
+/// a `ChaCha20Rng` when given (required for --coordinator/--worker and useful for
+/// reproducing a single-machine run), or from the OS RNG otherwise.
+fn compute_walk_start(seed: Option<u64>, space: u64) -> u64 {
+    match seed {
+        Some(seed) => {
+            eprintln!("Seeding walk start deterministically from seed {seed}");
+            let mut rng: rand_chacha::ChaCha20Rng = rand::SeedableRng::seed_from_u64(seed);
+            rand::Rng::random_range(&mut rng, 0..space)
+        }
+        None => {
+            let mut rng = rand::rngs::ThreadRng::default();
+            rand::Rng::random_range(&mut rng, 0..space)
+        }
+    }
+}
+
+/// Searches `range` (a sub-range of the `index_to_preimage` walk) in parallel over `pool`,
+/// running `command` against each candidate's derived key. Shared by the single-machine
+/// search, its checkpointed blocks, and a distributed `--worker`'s assigned ranges.
+fn search_subrange(
+    pool: &rayon::ThreadPool,
+    range: std::ops::Range<u64>,
+    walk_start: u64,
+    n_bits: u8,
+    salt: &[u8; SALT_SIZE],
+    kdf_params: &KdfParams,
+    command: &str,
+) -> Option<(String, String)> {
+    pool.install(|| {
+        range.into_par_iter().find_map_any(|idx| {
+            let preimage_bytes = index_to_preimage(idx, walk_start, n_bits);
+            let preimage_hex = hex::encode(preimage_bytes);
+            eprintln!("Deriving key for {preimage_hex}");
+            let derived_key = wskdf_core::wskdf_derive_key(&preimage_bytes, salt, &kdf_params.to_params())
+                .expect("derive key to complete");
+            let key_hex = hex::encode(derived_key);
+            if exec_and_send_to_stdin(key_hex.as_bytes(), command.to_string())
+                .map(|s| s.success())
+                .unwrap_or(false)
+            {
+                Some((preimage_hex, key_hex))
+            } else {
+                None
+            }
+        })
+    })
+}
+
+/// Number of candidates in one block of a `FindKey` search: a single-machine run searches
+/// one block at a time across its whole pool and flushes the checkpoint after each, and a
+/// `--coordinator` hands out one block per `--worker` assignment.
+const CHECKPOINT_BLOCK_SIZE: u64 = 1 << 16;
+
+/// A sparse bitmap over blocks of the candidate space: only the 64-block words that
+/// contain at least one set bit are materialized. This keeps a `FindKey` checkpoint small
+/// even though the space it tracks progress over can be as large as `2^62` candidates.
+#[derive(Default, Clone, serde::Serialize, serde::Deserialize)]
+struct BlockBitmap {
+    words: std::collections::BTreeMap<u64, u64>,
+}
+
+impl BlockBitmap {
+    fn is_set(&self, block: u64) -> bool {
+        let (word, bit) = (block / 64, block % 64);
+        self.words.get(&word).is_some_and(|w| w & (1 << bit) != 0)
+    }
+
+    fn set(&mut self, block: u64) {
+        let (word, bit) = (block / 64, block % 64);
+        *self.words.entry(word).or_insert(0) |= 1 << bit;
+    }
+
+    fn count_set(&self) -> u32 {
+        self.words.values().map(|w| w.count_ones()).sum()
+    }
+}
+
+/// On-disk shape of a `FindKey --checkpoint` file: the bitmap of completed blocks plus the
+/// parameters it was recorded under, so `--resume` can refuse to reuse it for a different
+/// search.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct Checkpoint {
+    n_bits: u8,
+    salt_hex: String,
+    kdf_params: KdfParams,
+    bitmap: BlockBitmap,
+}
+
+fn load_checkpoint(path: &std::path::Path) -> anyhow::Result<Checkpoint> {
+    let bytes = std::fs::read(path)
+        .with_context(|| format!("failed to read checkpoint file {}", path.display()))?;
+    serde_json::from_slice(&bytes)
+        .with_context(|| format!("failed to parse checkpoint file {}", path.display()))
+}
+
+fn save_checkpoint(
+    path: &PathBuf,
+    n_bits: u8,
+    salt: &[u8; SALT_SIZE],
+    kdf_params: &KdfParams,
+    bitmap: &BlockBitmap,
+) -> anyhow::Result<()> {
+    let checkpoint = Checkpoint {
+        n_bits,
+        salt_hex: hex::encode(salt),
+        kdf_params: kdf_params.clone(),
+        bitmap: bitmap.clone(),
+    };
+    let tmp_path = path.with_extension("tmp");
+    std::fs::write(&tmp_path, serde_json::to_vec(&checkpoint)?)
+        .with_context(|| format!("failed to write checkpoint file {}", tmp_path.display()))?;
+    std::fs::rename(&tmp_path, path)
+        .with_context(|| format!("failed to finalize checkpoint file {}", path.display()))?;
+    Ok(())
+}
+
+/// Messages a `--worker` sends to the `--coordinator` over its length-prefixed TCP stream.
+#[derive(serde::Serialize, serde::Deserialize)]
+enum ToCoordinator {
+    /// First message on a new connection: negotiates that both sides agree on the search
+    /// they're running before any ranges are handed out.
+    Hello {
+        n_bits: u8,
+        salt_hex: String,
+        kdf_params: KdfParams,
+        seed: u64,
+    },
+    /// The assigned range was exhausted with no match; doubles as a heartbeat, sent once
+    /// per completed block.
+    BlockDone { candidates_searched: u64 },
+    Found { preimage_hex: String, key_hex: String },
+}
+
+/// Messages a `--coordinator` sends to a `--worker` over its length-prefixed TCP stream.
+#[derive(serde::Serialize, serde::Deserialize)]
+enum ToWorker {
+    Accepted,
+    Rejected { reason: String },
+    /// A contiguous, disjoint sub-range of the `index_to_preimage` walk to search locally.
+    Assign { start: u64, end: u64 },
+    /// No more ranges are coming, either because the space is exhausted or another worker
+    /// already found the match; the worker should disconnect.
+    Stop,
+}
+
+fn send_message<T: serde::Serialize>(
+    stream: &mut impl Write,
+    message: &T,
+) -> anyhow::Result<()> {
+    let bytes = serde_json::to_vec(message)?;
+    let len = u32::try_from(bytes.len()).context("message too large to frame")?;
+    stream.write_all(&len.to_be_bytes())?;
+    stream.write_all(&bytes)?;
+    stream.flush()?;
+    Ok(())
+}
+
+/// Largest framed message `recv_message` will allocate a buffer for. Real `ToCoordinator`/
+/// `ToWorker` messages are a few dozen bytes; this just bounds what an untrusted peer can
+/// make us allocate before the JSON is even parsed.
+const MAX_MESSAGE_SIZE: usize = 8 * 1024 * 1024;
+
+fn recv_message<T: serde::de::DeserializeOwned>(
+    stream: &mut impl Read,
+) -> anyhow::Result<T> {
+    let mut len_bytes = [0u8; 4];
+    stream.read_exact(&mut len_bytes)?;
+    let len = u32::from_be_bytes(len_bytes) as usize;
+    ensure!(
+        len <= MAX_MESSAGE_SIZE,
+        "message length {len} exceeds the {MAX_MESSAGE_SIZE}-byte limit"
+    );
+    let mut buf = vec![0u8; len];
+    stream.read_exact(&mut buf)?;
+    serde_json::from_slice(&buf).context("failed to parse message")
+}
+
+/// Runs `FindKey --coordinator`: shards the `[0, space)` walk into `CHECKPOINT_BLOCK_SIZE`
+/// blocks and hands one at a time to each connecting worker. Polls a non-blocking
+/// `listener.accept` every 50ms, handling each worker connection on its own thread, until a
+/// worker reports a match or the whole space has been exhausted.
+#[allow(clippy::too_many_arguments)]
+fn run_coordinator(
+    addr: std::net::SocketAddr,
+    n_bits: u8,
+    salt: &[u8; SALT_SIZE],
+    kdf_params: &KdfParams,
+    seed: u64,
+    space: u64,
+    num_blocks: u64,
+    preimage_output: &PathBuf,
+    key_output: &Option<PathBuf>,
+) -> anyhow::Result<()> {
+    let listener = std::net::TcpListener::bind(addr)
+        .with_context(|| format!("failed to bind coordinator listener on {addr}"))?;
+    eprintln!(
+        "Coordinator listening on {addr}, sharding {num_blocks} blocks of {CHECKPOINT_BLOCK_SIZE} candidates each"
+    );
+    listener
+        .set_nonblocking(true)
+        .context("failed to set coordinator listener non-blocking")?;
+
+    let next_block = AtomicU64::new(0);
+    let blocks_done = AtomicU64::new(0);
+    let result: std::sync::Mutex<Option<(String, String)>> = std::sync::Mutex::new(None);
+
+    std::thread::scope(|scope| {
+        loop {
+            if result.lock().expect("result lock poisoned").is_some()
+                || blocks_done.load(Ordering::SeqCst) >= num_blocks
+            {
+                break;
+            }
+            match listener.accept() {
+                Ok((mut stream, peer)) => {
+                    let next_block = &next_block;
+                    let blocks_done = &blocks_done;
+                    let result = &result;
+                    scope.spawn(move || {
+                        if let Err(err) = handle_worker_connection(
+                            &mut stream,
+                            n_bits,
+                            salt,
+                            kdf_params,
+                            seed,
+                            space,
+                            num_blocks,
+                            next_block,
+                            blocks_done,
+                            result,
+                        ) {
+                            eprintln!("Worker {peer} disconnected: {err:#}");
+                        }
+                    });
+                }
+                Err(err) if err.kind() == std::io::ErrorKind::WouldBlock => {
+                    std::thread::sleep(std::time::Duration::from_millis(50));
+                }
+                Err(err) => eprintln!("Failed to accept worker connection: {err}"),
+            }
+        }
+    });
+
+    match result.into_inner().expect("result lock poisoned") {
+        Some((preimage_hex, key_hex)) => {
+            write_file(preimage_output, &preimage_hex)?;
+            if let Some(key_output) = key_output {
+                write_file(key_output, &key_hex)?;
+            }
+            Ok(())
+        }
+        None => anyhow::bail!("search space exhausted across all workers without a match"),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn handle_worker_connection(
+    stream: &mut std::net::TcpStream,
+    n_bits: u8,
+    salt: &[u8; SALT_SIZE],
+    kdf_params: &KdfParams,
+    seed: u64,
+    space: u64,
+    num_blocks: u64,
+    next_block: &AtomicU64,
+    blocks_done: &AtomicU64,
+    result: &std::sync::Mutex<Option<(String, String)>>,
+) -> anyhow::Result<()> {
+    let hello: ToCoordinator = recv_message(stream)?;
+    let ToCoordinator::Hello {
+        n_bits: worker_n_bits,
+        salt_hex: worker_salt_hex,
+        kdf_params: worker_kdf_params,
+        seed: worker_seed,
+    } = hello
+    else {
+        anyhow::bail!("expected Hello as the first message from a worker");
+    };
+    if worker_n_bits != n_bits
+        || worker_salt_hex != hex::encode(salt)
+        || worker_kdf_params != *kdf_params
+        || worker_seed != seed
+    {
+        send_message(
+            stream,
+            &ToWorker::Rejected {
+                reason: "n_bits/salt/kdf_params/seed do not match the coordinator's".to_string(),
+            },
+        )?;
+        anyhow::bail!("worker negotiation failed: parameter mismatch");
+    }
+    send_message(stream, &ToWorker::Accepted)?;
+    eprintln!("Worker negotiated successfully");
+
+    loop {
+        if result.lock().expect("result lock poisoned").is_some() {
+            send_message(stream, &ToWorker::Stop)?;
+            return Ok(());
+        }
+        let start = next_block.fetch_add(CHECKPOINT_BLOCK_SIZE, Ordering::SeqCst);
+        if start >= space {
+            send_message(stream, &ToWorker::Stop)?;
+            return Ok(());
+        }
+        let end = (start + CHECKPOINT_BLOCK_SIZE).min(space);
+        send_message(stream, &ToWorker::Assign { start, end })?;
+
+        match recv_message(stream)? {
+            ToCoordinator::BlockDone { candidates_searched } => {
+                let done = blocks_done.fetch_add(1, Ordering::SeqCst) + 1;
+                eprintln!("{done}/{num_blocks} blocks done ({candidates_searched} candidates this block)");
+            }
+            ToCoordinator::Found {
+                preimage_hex,
+                key_hex,
+            } => {
+                eprintln!("Worker reported a match: {preimage_hex}");
+                *result.lock().expect("result lock poisoned") = Some((preimage_hex, key_hex));
+                send_message(stream, &ToWorker::Stop)?;
+                return Ok(());
+            }
+            ToCoordinator::Hello { .. } => {
+                anyhow::bail!("unexpected Hello from a worker after negotiation")
+            }
+        }
+    }
+}
+
+/// Runs `FindKey --worker`: negotiates with the coordinator, then repeatedly searches
+/// whatever `Assign`ed range it's handed (in parallel, over its own local rayon `pool`)
+/// until the coordinator sends `Stop`.
+#[allow(clippy::too_many_arguments)]
+fn run_worker(
+    coordinator_addr: std::net::SocketAddr,
+    n_bits: u8,
+    salt: &[u8; SALT_SIZE],
+    kdf_params: &KdfParams,
+    seed: u64,
+    threads: usize,
+    preimage_output: &PathBuf,
+    key_output: &Option<PathBuf>,
+    command: &str,
+) -> anyhow::Result<()> {
+    let mut stream = std::net::TcpStream::connect(coordinator_addr)
+        .with_context(|| format!("failed to connect to coordinator at {coordinator_addr}"))?;
+    send_message(
+        &mut stream,
+        &ToCoordinator::Hello {
+            n_bits,
+            salt_hex: hex::encode(salt),
+            kdf_params: kdf_params.clone(),
+            seed,
+        },
+    )?;
+    match recv_message(&mut stream)? {
+        ToWorker::Accepted => eprintln!("Negotiated with coordinator at {coordinator_addr}"),
+        ToWorker::Rejected { reason } => {
+            anyhow::bail!("coordinator rejected negotiation: {reason}")
+        }
+        ToWorker::Assign { .. } | ToWorker::Stop => {
+            anyhow::bail!("unexpected message from coordinator during negotiation")
+        }
+    }
+
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()
+        .context("failed to build rayon pool")?;
+    let space = 1u64 << (n_bits - 1);
+    let walk_start = compute_walk_start(Some(seed), space);
+
+    loop {
+        match recv_message(&mut stream)? {
+            ToWorker::Assign { start, end } => {
+                eprintln!("Assigned range [{start}, {end})");
+                match search_subrange(&pool, start..end, walk_start, n_bits, salt, kdf_params, command) {
+                    Some((preimage_hex, key_hex)) => {
+                        write_file(preimage_output, &preimage_hex)?;
+                        if let Some(key_output) = key_output {
+                            write_file(key_output, &key_hex)?;
+                        }
+                        send_message(
+                            &mut stream,
+                            &ToCoordinator::Found {
+                                preimage_hex,
+                                key_hex,
+                            },
+                        )?;
+                        return Ok(());
+                    }
+                    None => {
+                        send_message(
+                            &mut stream,
+                            &ToCoordinator::BlockDone {
+                                candidates_searched: end - start,
+                            },
+                        )?;
+                    }
+                }
+            }
+            ToWorker::Stop => {
+                eprintln!("Coordinator signaled stop");
+                return Ok(());
+            }
+            ToWorker::Accepted | ToWorker::Rejected { .. } => {
+                anyhow::bail!("unexpected message from coordinator after negotiation")
+            }
+        }
+    }
+}
+
 fn exec_and_send_to_stdin(
     bytes: &[u8],
     command: String,
@@ -550,14 +1261,287 @@ fn percentile_multiplier(percentile: f64) -> f64 {
     -((1.0 - percentile).ln())
 }
 
-/// Estimation results for a given bit length
+/// Number of real derivations run per thread to calibrate [`calibrate_derivation_time`].
+const CALIBRATION_SAMPLES_PER_THREAD: usize = 8;
+
+/// Measures real per-derivation time on this machine by running a handful of actual
+/// `wskdf_derive_key` calls across `threads` rayon workers, mirroring the measurement
+/// approach used by `Commands::Benchmark`. Used to calibrate `FindKey`'s completion-time
+/// estimates instead of a hardcoded placeholder.
+fn calibrate_derivation_time(
+    pool: &rayon::ThreadPool,
+    salt: &[u8; SALT_SIZE],
+    kdf_params: &KdfParams,
+    threads: usize,
+) -> anyhow::Result<f64> {
+    let params = kdf_params.to_params();
+    let preimage = wskdf_core::gen_rand_preimage(32)?;
+
+    let total_samples = CALIBRATION_SAMPLES_PER_THREAD * threads;
+    let start = std::time::Instant::now();
+    pool.install(|| {
+        (0..total_samples).into_par_iter().for_each(|_| {
+            wskdf_core::wskdf_derive_key(&preimage, salt, &params).expect("derive key to complete");
+        });
+    });
+    let elapsed = start.elapsed().as_secs_f64();
+    Ok(elapsed / CALIBRATION_SAMPLES_PER_THREAD as f64)
+}
+
+/// Measures the wall-clock time of a single derivation at the given `ops_limit`,
+/// running one derivation per pool thread in parallel so a single measurement covers
+/// all of them.
+fn measure_single_derivation_secs(
+    pool: &rayon::ThreadPool,
+    preimage: &[u8; PREIMAGE_SIZE],
+    salt: &[u8; SALT_SIZE],
+    ops_limit: u32,
+    mem_limit_kbytes: u32,
+) -> anyhow::Result<f64> {
+    let params = wskdf_core::Params::new(ops_limit, mem_limit_kbytes);
+    let start = std::time::Instant::now();
+    pool.install(|| {
+        (0..pool.current_num_threads())
+            .into_par_iter()
+            .for_each(|_| {
+                wskdf_core::wskdf_derive_key(preimage, salt, &params)
+                    .expect("derive key to complete");
+            });
+    });
+    Ok(start.elapsed().as_secs_f64())
+}
+
+/// Relative tolerance within which a measured derivation time is considered to match
+/// `target_secs` in [`calibrate_ops_limit`].
+const CALIBRATION_TOLERANCE: f64 = 0.1;
+
+/// Finds an `ops_limit` (at a fixed `mem_limit_kbytes`) whose single-derivation time is
+/// within [`CALIBRATION_TOLERANCE`] of `target_secs`, first doubling `ops_limit` until
+/// the measured time brackets the target, then bisecting within the bracket.
+fn calibrate_ops_limit(
+    pool: &rayon::ThreadPool,
+    preimage: &[u8; PREIMAGE_SIZE],
+    salt: &[u8; SALT_SIZE],
+    mem_limit_kbytes: u32,
+    target_secs: f64,
+) -> anyhow::Result<u32> {
+    let measure = |ops_limit: u32| -> anyhow::Result<f64> {
+        let secs =
+            measure_single_derivation_secs(pool, preimage, salt, ops_limit, mem_limit_kbytes)?;
+        eprintln!("ops_limit={ops_limit} -> {}", pretty(secs));
+        Ok(secs)
+    };
+
+    let within_tolerance =
+        |secs: f64| ((secs - target_secs).abs() / target_secs) <= CALIBRATION_TOLERANCE;
+
+    let mut low = 1u32;
+    let mut low_secs = measure(low)?;
+    if within_tolerance(low_secs) {
+        return Ok(low);
+    }
+    anyhow::ensure!(
+        low_secs < target_secs,
+        "ops_limit=1 already takes {} (longer than target {}s); lower target_secs or mem_limit_kbytes",
+        pretty(low_secs),
+        target_secs
+    );
+
+    let mut high = low;
+    let mut high_secs = low_secs;
+    while high_secs < target_secs {
+        anyhow::ensure!(
+            high < u32::MAX / 2,
+            "ops_limit overflowed while searching for target_secs"
+        );
+        low = high;
+        low_secs = high_secs;
+        high *= 2;
+        high_secs = measure(high)?;
+    }
+    if within_tolerance(high_secs) {
+        return Ok(high);
+    }
+
+    while high - low > 1 {
+        let mid = low + (high - low) / 2;
+        let mid_secs = measure(mid)?;
+        if within_tolerance(mid_secs) {
+            return Ok(mid);
+        }
+        if mid_secs < target_secs {
+            low = mid;
+            low_secs = mid_secs;
+        } else {
+            high = mid;
+            high_secs = mid_secs;
+        }
+    }
+
+    // Neither endpoint was within tolerance; return whichever is closer to the target.
+    if (low_secs - target_secs).abs() <= (high_secs - target_secs).abs() {
+        Ok(low)
+    } else {
+        Ok(high)
+    }
+}
+
+fn mean(samples: &[f64]) -> f64 {
+    samples.iter().sum::<f64>() / samples.len() as f64
+}
+
+fn stddev(samples: &[f64], mean: f64) -> f64 {
+    let variance = samples.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / samples.len() as f64;
+    variance.sqrt()
+}
+
+fn median(samples: &[f64]) -> f64 {
+    let mut sorted = samples.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let mid = sorted.len() / 2;
+    if sorted.len() % 2 == 0 {
+        (sorted[mid - 1] + sorted[mid]) / 2.0
+    } else {
+        sorted[mid]
+    }
+}
+
+/// Multiple of the median absolute deviation beyond which [`reject_outliers`] drops a
+/// sample.
+const OUTLIER_MAD_MULTIPLIER: f64 = 3.0;
+
+/// Drops samples more than [`OUTLIER_MAD_MULTIPLIER`]×MAD from the median, so a single
+/// stalled/boosted measurement can't skew the mean.
+fn reject_outliers(samples: Vec<f64>) -> Vec<f64> {
+    let center = median(&samples);
+    let deviations: Vec<f64> = samples.iter().map(|s| (s - center).abs()).collect();
+    let mad = median(&deviations);
+    if mad == 0.0 {
+        return samples;
+    }
+    let threshold = OUTLIER_MAD_MULTIPLIER * mad;
+    samples
+        .into_iter()
+        .filter(|s| (s - center).abs() <= threshold)
+        .collect()
+}
+
+/// Summary statistics produced by [`run_adaptive_benchmark`], one sample per measurement
+/// batch, each sample already normalized to seconds-per-derivation.
+#[derive(Debug, Clone, Copy)]
+pub struct BenchmarkStats {
+    pub min_secs: f64,
+    pub median_secs: f64,
+    pub mean_secs: f64,
+    pub stddev_secs: f64,
+    pub derivations_per_second: f64,
+    pub samples_used: usize,
+    pub samples_discarded: usize,
+}
+
+/// Minimum number of post-warmup samples collected before the relative-stderr stopping
+/// rule is allowed to end the loop; otherwise a couple of lucky batches could pass
+/// `target_relative_stderr` by chance.
+const BENCHMARK_MIN_SAMPLES: usize = 5;
+
+/// Adaptively benchmarks `wskdf_derive_key`: `warmup_batches` batches are run and
+/// discarded to let the allocator/cache/frequency scaling settle, then batches keep
+/// accumulating samples until either
+/// `target_relative_stderr` (stddev / mean / sqrt(n)) is reached or `bench_max_secs` of
+/// wall-clock time elapses. Outliers are rejected via median-absolute-deviation before the
+/// final statistics are computed.
+#[allow(clippy::too_many_arguments)]
+fn run_adaptive_benchmark(
+    pool: &rayon::ThreadPool,
+    preimage: &[u8; PREIMAGE_SIZE],
+    salt: &[u8; SALT_SIZE],
+    kdf_params: &KdfParams,
+    threads: usize,
+    iterations: usize,
+    warmup_batches: usize,
+    bench_max_secs: f64,
+    target_relative_stderr: f64,
+) -> BenchmarkStats {
+    let params = kdf_params.to_params();
+    let total_per_batch = iterations * threads;
+
+    let run_batch = || -> f64 {
+        let start = std::time::Instant::now();
+        pool.install(|| {
+            (0..total_per_batch).into_par_iter().for_each(|_| {
+                wskdf_core::wskdf_derive_key(preimage, salt, &params)
+                    .expect("key derivation to work");
+            });
+        });
+        start.elapsed().as_secs_f64() / iterations as f64
+    };
+
+    for batch in 0..warmup_batches {
+        eprintln!("Warmup batch {}/{warmup_batches}...", batch + 1);
+        run_batch();
+    }
+
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs_f64(bench_max_secs);
+    let mut samples = Vec::new();
+    loop {
+        samples.push(run_batch());
+
+        if samples.len() >= BENCHMARK_MIN_SAMPLES {
+            let batch_mean = mean(&samples);
+            let relative_stderr =
+                stddev(&samples, batch_mean) / batch_mean / (samples.len() as f64).sqrt();
+            if relative_stderr <= target_relative_stderr {
+                break;
+            }
+        }
+        if std::time::Instant::now() >= deadline {
+            eprintln!("Reached --bench-max-secs before converging; reporting what we have");
+            break;
+        }
+    }
+
+    let samples_before = samples.len();
+    let samples = reject_outliers(samples);
+    let samples_discarded = samples_before - samples.len();
+
+    let mean_secs = mean(&samples);
+    BenchmarkStats {
+        min_secs: samples.iter().copied().fold(f64::INFINITY, f64::min),
+        median_secs: median(&samples),
+        mean_secs,
+        stddev_secs: stddev(&samples, mean_secs),
+        derivations_per_second: 1.0 / mean_secs,
+        samples_used: samples.len(),
+        samples_discarded,
+    }
+}
+
+/// Time by which `p` of random-search runs have completed. A match is equally likely at
+/// any position, so the number of derivations until a hit is (approximately)
+/// exponentially distributed, and the tail at percentile `p` is `-ln(1-p)` times the
+/// expected time — `p=0.99` reproduces [`calculate_random_times`]'s ≈4.605 multiplier.
+pub fn random_percentile(space: f64, threads: usize, avg_time_secs: f64, p: f64) -> f64 {
+    let (expected_secs, _, _) = calculate_random_times(space, threads, avg_time_secs);
+    expected_secs * percentile_multiplier(p)
+}
+
+/// Time by which `p` of systematic-search runs have completed. The target position is
+/// uniform over each thread's partition, so the fraction of the worst-case time scanned
+/// at percentile `p` is exactly `p`.
+pub fn systematic_percentile(space: f64, threads: usize, avg_time_secs: f64, p: f64) -> f64 {
+    let (_, worst_secs) = calculate_systematic_times(space, threads, avg_time_secs);
+    p * worst_secs
+}
+
+/// Estimation results for a given bit length, at a caller-chosen set of percentiles.
 #[derive(Debug, PartialEq)]
 pub struct EstimationResult {
     pub systematic_expected_secs: f64,
-    pub systematic_worst_secs: f64,
     pub random_expected_secs: f64,
-    pub random_99th_percentile_secs: f64,
-    pub random_999th_percentile_secs: f64,
+    /// `(percentile, seconds)` pairs for systematic search, in the order requested.
+    pub systematic_percentiles: Vec<(f64, f64)>,
+    /// `(percentile, seconds)` pairs for random search, in the order requested.
+    pub random_percentiles: Vec<(f64, f64)>,
 }
 
 /// Calculate search space size for n-bit preimages
@@ -593,66 +1577,441 @@ pub fn calculate_random_times(space: f64, threads: usize, avg_time_secs: f64) ->
     (expected_secs, p99_secs, p999_secs)
 }
 
-/// Calculate all estimation results for a given bit length
+/// A per-derivation time distribution to draw from in [`simulate_search`].
+#[derive(Debug, Clone, Copy)]
+pub enum Dist {
+    /// Every derivation takes exactly this many seconds.
+    Constant(f64),
+    /// Derivation time varies log-normally around a measured `mean`/`stddev`, as fit from a
+    /// benchmark run rather than assumed constant.
+    LogNormal { mean: f64, stddev: f64 },
+}
+
+impl Dist {
+    fn mean(&self) -> f64 {
+        match *self {
+            Dist::Constant(mean) | Dist::LogNormal { mean, .. } => mean,
+        }
+    }
+
+    fn sample(&self, rng: &mut impl rand::Rng) -> f64 {
+        match *self {
+            Dist::Constant(mean) => mean,
+            Dist::LogNormal { mean, stddev } => {
+                // Fit the underlying normal's (mu, sigma) from the lognormal's mean/stddev.
+                let variance = stddev * stddev;
+                let sigma2 = (1.0 + variance / (mean * mean)).ln();
+                let mu = mean.ln() - sigma2 / 2.0;
+                let dist = rand_distr::LogNormal::new(mu, sigma2.sqrt())
+                    .expect("mean/stddev should fit a valid lognormal");
+                rand_distr::Distribution::sample(&dist, rng)
+            }
+        }
+    }
+}
+
+/// Which search strategy [`simulate_search`] is modeling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    /// Each thread independently redraws a random candidate, so the number of derivations
+    /// until a match is geometrically distributed.
+    Random,
+    /// Threads systematically partition the space, so the number of derivations is however
+    /// far into the space (divided across threads) the target happens to land.
+    Systematic,
+}
+
+/// Above this many derivations in a single sample, approximate their total time as
+/// `derivations * per_derivation.mean()` instead of drawing each one individually, so a
+/// large `samples` count over a large search space stays fast.
+const SIMULATE_SEARCH_EXACT_DRAW_LIMIT: u64 = 1_000;
+
+/// A sampled wall-clock completion-time distribution produced by [`simulate_search`].
+#[derive(Debug, Clone)]
+pub struct Distribution {
+    pub mean: f64,
+    pub stddev: f64,
+    /// Sorted ascending, so [`Distribution::percentile`] can index directly.
+    samples: Vec<f64>,
+}
+
+impl Distribution {
+    fn from_samples(mut samples: Vec<f64>) -> Self {
+        samples.sort_by(|a, b| a.total_cmp(b));
+        let n = samples.len() as f64;
+        let mean = samples.iter().sum::<f64>() / n;
+        let variance = samples.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / n;
+        Self {
+            mean,
+            stddev: variance.sqrt(),
+            samples,
+        }
+    }
+
+    /// The value below which `p` of the samples fall, e.g. `percentile(0.99)` is the 99th
+    /// percentile.
+    pub fn percentile(&self, p: f64) -> f64 {
+        let idx = ((p * self.samples.len() as f64).ceil() as usize)
+            .saturating_sub(1)
+            .min(self.samples.len() - 1);
+        self.samples[idx]
+    }
+
+    pub fn p50(&self) -> f64 {
+        self.percentile(0.50)
+    }
+
+    pub fn p90(&self) -> f64 {
+        self.percentile(0.90)
+    }
+
+    pub fn p99(&self) -> f64 {
+        self.percentile(0.99)
+    }
+
+    pub fn p999(&self) -> f64 {
+        self.percentile(0.999)
+    }
+}
+
+/// Monte Carlo estimate of wall-clock completion time, sampling `samples` simulated
+/// searches instead of relying on [`calculate_random_times`]/[`calculate_systematic_times`]'s
+/// closed-form point values. Real per-derivation time isn't a constant average, so drawing
+/// it from `per_derivation` each trial gives honest percentile bands instead of a single
+/// analytic number.
+pub fn simulate_search(
+    bits: u8,
+    threads: usize,
+    per_derivation: Dist,
+    strategy: Strategy,
+    samples: usize,
+) -> Distribution {
+    let space = calculate_search_space(bits);
+    let mut rng = rand::rngs::ThreadRng::default();
+
+    let wall_clock_samples = (0..samples)
+        .map(|_| {
+            // `derivations` and whether its time still needs dividing across `threads`
+            // differs by strategy: Random's geometric draw is the serial-equivalent trial
+            // count across all threads, while Systematic's round-robin index is already the
+            // owning thread's own derivation count.
+            let (derivations, divide_by_threads) = match strategy {
+                Strategy::Random => {
+                    // Geometric(1/space): number of failures before the first success,
+                    // matching the exponential-tail model `calculate_random_times` assumes.
+                    let geometric = rand_distr::Geometric::new(space.recip())
+                        .expect("1/space should be a valid geometric parameter");
+                    let derivations = rand_distr::Distribution::sample(&geometric, &mut rng) + 1_u64;
+                    (derivations, true)
+                }
+                Strategy::Systematic => {
+                    let position = rand::Rng::random_range(&mut rng, 1.0..=space);
+                    let derivations = (position / threads as f64).ceil() as u64;
+                    (derivations, false)
+                }
+            };
+
+            let total_time = if derivations > SIMULATE_SEARCH_EXACT_DRAW_LIMIT {
+                derivations as f64 * per_derivation.mean()
+            } else {
+                (0..derivations).map(|_| per_derivation.sample(&mut rng)).sum()
+            };
+
+            if divide_by_threads {
+                total_time / threads as f64
+            } else {
+                total_time
+            }
+        })
+        .collect();
+
+    Distribution::from_samples(wall_clock_samples)
+}
+
+/// Calculate all estimation results for a given bit length, reporting `percentiles` (each
+/// a fraction in `[0.0, 1.0)`) for both search strategies.
 pub fn calculate_estimation_for_bits(
     bits: u8,
     threads: usize,
     avg_time_secs: f64,
+    percentiles: &[f64],
 ) -> EstimationResult {
     let space = calculate_search_space(bits);
-    let (systematic_expected, systematic_worst) =
-        calculate_systematic_times(space, threads, avg_time_secs);
-    let (random_expected, random_99th, random_999th) =
-        calculate_random_times(space, threads, avg_time_secs);
+    let (systematic_expected, _) = calculate_systematic_times(space, threads, avg_time_secs);
+    let (random_expected, _, _) = calculate_random_times(space, threads, avg_time_secs);
 
     EstimationResult {
         systematic_expected_secs: systematic_expected,
-        systematic_worst_secs: systematic_worst,
         random_expected_secs: random_expected,
-        random_99th_percentile_secs: random_99th,
-        random_999th_percentile_secs: random_999th,
+        systematic_percentiles: percentiles
+            .iter()
+            .map(|&p| (p, systematic_percentile(space, threads, avg_time_secs, p)))
+            .collect(),
+        random_percentiles: percentiles
+            .iter()
+            .map(|&p| (p, random_percentile(space, threads, avg_time_secs, p)))
+            .collect(),
     }
 }
 
-fn pretty(secs: f64) -> String {
-    const MIN: f64 = 60.0;
-    const H: f64 = 60.0 * MIN;
-    const D: f64 = 24.0 * H;
-    const Y: f64 = 365.0 * D; // year approximation (365 days)
-
-    // pick the main unit and how much time is left over
-    let (whole, unit, rest) = if secs < MIN {
-        (secs, "s", 0.0)
-    } else if secs < H {
-        let whole = (secs / MIN).floor();
-        (whole, "min", secs - whole * MIN)
-    } else if secs < D {
-        let whole = (secs / H).floor();
-        (whole, "h", secs - whole * H)
-    } else if secs < Y {
-        let whole = (secs / D).floor();
-        (whole, "d", secs - whole * D)
+const MIN: f64 = 60.0;
+const H: f64 = 60.0 * MIN;
+const D: f64 = 24.0 * H;
+const Y: f64 = 365.0 * D; // year approximation (365 days)
+
+/// Units a duration is rendered with when it's at least a second, coarsest first. Each
+/// component is paired with the next one down (e.g. "5d 17h"), rounded to the nearest
+/// whole count, except that a last-shown seconds component keeps its fractional part
+/// (e.g. "31.5s").
+const COARSE_UNITS: &[(&str, f64)] = &[("y", Y), ("d", D), ("h", H), ("min", MIN), ("s", 1.0)];
+
+/// Units a sub-second duration is rendered with, coarsest first. As with seconds in
+/// [`COARSE_UNITS`], the last shown component keeps its fractional part (trimmed), since a derivation's
+/// wall-clock time is the whole point of measuring it at this resolution.
+const FINE_UNITS: &[(&str, f64)] = &[("ms", 1e-3), ("µs", 1e-6), ("ns", 1e-9)];
+
+/// Units whose last-shown component [`pretty_with_precision`] renders with a fractional
+/// part (trimmed of trailing zeros) rather than rounding to a whole count.
+fn unit_keeps_fraction(name: &str) -> bool {
+    matches!(name, "s" | "ms" | "µs" | "ns")
+}
+
+/// Formats the last shown component of a duration as a whole count when its unit has no
+/// fractional part: rounded to the nearest whole count when `round` is set (a secondary
+/// component, e.g. the "17h" in "5d 17h"), floored otherwise (a primary component that is
+/// also the only one shown, e.g. "5d" for 5.69 days).
+fn format_whole_component(value: f64, name: &str, round: bool) -> String {
+    let whole = if round { value.round() } else { value.floor() };
+    format!("{whole:.0}{name}")
+}
+
+/// Formats the last shown component when its unit keeps a fractional part (seconds and
+/// sub-second units), trimming trailing zeros so e.g. "30s" stays bare but "31.5s" keeps
+/// its fraction.
+fn format_fractional_component(value: f64, name: &str) -> String {
+    if (value - value.round()).abs() < 1e-9 {
+        format!("{:.0}{name}", value.round())
     } else {
-        let whole = (secs / Y).floor();
-        (whole, "y", secs - whole * Y)
-    };
+        let formatted = format!("{value:.3}");
+        format!("{}{name}", formatted.trim_end_matches('0').trim_end_matches('.'))
+    }
+}
 
-    // render the next smaller unit, rounded to the nearest integer
-    let second = match unit {
-        "y" => format!(" {:.0}d", (rest / D).round()),
-        "d" => format!(" {:.0}h", (rest / H).round()),
-        "h" => format!(" {:.0}min", (rest / MIN).round()),
-        "min" => format!(" {:.0}s", rest.round()),
-        _ => String::new(),
-    };
+/// Formats `secs` as up to `precision` leading magnitude components (e.g. `precision=3`
+/// renders "5d 17h 3min" where `precision=1` would render just "5d"), switching to
+/// sub-second units (ms/µs/ns) below one second so a single fast derivation stays legible.
+/// [`parse_duration`] reads the output back to the value of the components shown; the
+/// remainder below the last shown component is dropped.
+pub fn pretty_with_precision(secs: f64, precision: usize) -> String {
+    assert!(precision >= 1, "precision must be >= 1");
+    if secs == 0.0 {
+        return "0s".to_string();
+    }
+
+    let units = if secs < 1.0 { FINE_UNITS } else { COARSE_UNITS };
+    let start = units
+        .iter()
+        .position(|&(_, unit_secs)| secs >= unit_secs)
+        .unwrap_or(units.len() - 1);
+
+    let mut remaining = secs;
+    let mut parts = Vec::new();
+    for (i, &(name, unit_secs)) in units[start..].iter().enumerate() {
+        let is_last = i + 1 == precision || start + i + 1 == units.len();
+        let value = remaining / unit_secs;
+        if is_last {
+            let part = if unit_keeps_fraction(name) {
+                format_fractional_component(value, name)
+            } else {
+                // The primary component (i == 0) always truncates, even when it's also
+                // the last one shown (e.g. `precision=1` on 5.69 days stays "5d", not
+                // "6d"); a secondary-and-last component rounds, as it always has.
+                format_whole_component(value, name, i > 0)
+            };
+            parts.push(part);
+            break;
+        }
+        let whole = value.floor();
+        parts.push(format!("{whole:.0}{name}"));
+        remaining -= whole * unit_secs;
+    }
+    parts.join(" ")
+}
 
-    format!("{whole:.0}{unit}{second}")
+/// Formats `secs` the way [`pretty_with_precision`] does at its original, fixed
+/// precision of two components (e.g. "5d 17h", "1min 0s").
+fn pretty(secs: f64) -> String {
+    pretty_with_precision(secs, 2)
+}
+
+/// Parses a duration formatted by [`pretty`]/[`pretty_with_precision`] (e.g. "5d 17h",
+/// "2h 8min", "31.5s", "450ms") back into seconds. A bare number with no unit is accepted
+/// as a plain second count, so inputs like `--bench-max-secs 30` keep working.
+pub fn parse_duration(s: &str) -> anyhow::Result<f64> {
+    let s = s.trim();
+    if let Ok(secs) = s.parse::<f64>() {
+        return Ok(secs);
+    }
+
+    let mut total = 0.0;
+    let mut found_any = false;
+    for token in s.split_whitespace() {
+        let split_at = token
+            .find(|c: char| !c.is_ascii_digit() && c != '.' && c != '-')
+            .ok_or_else(|| anyhow::anyhow!("duration component {token:?} is missing a unit"))?;
+        let (value_str, unit) = token.split_at(split_at);
+        let value: f64 = value_str
+            .parse()
+            .with_context(|| format!("invalid number in duration component {token:?}"))?;
+        let unit_secs = COARSE_UNITS
+            .iter()
+            .chain(FINE_UNITS)
+            .find(|&&(name, _)| name == unit || (name == "µs" && unit == "us"))
+            .map(|&(_, unit_secs)| unit_secs)
+            .ok_or_else(|| anyhow::anyhow!("unknown duration unit {unit:?} in {token:?}"))?;
+        total += value * unit_secs;
+        found_any = true;
+    }
+    anyhow::ensure!(found_any, "empty duration string {s:?}");
+    Ok(total)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_block_bitmap_set_and_is_set() {
+        let mut bitmap = BlockBitmap::default();
+        assert!(!bitmap.is_set(0));
+        assert!(!bitmap.is_set(63));
+        assert!(!bitmap.is_set(64));
+
+        bitmap.set(0);
+        bitmap.set(63);
+        bitmap.set(64); // first bit of the next word
+        bitmap.set(200);
+
+        assert!(bitmap.is_set(0));
+        assert!(bitmap.is_set(63));
+        assert!(bitmap.is_set(64));
+        assert!(bitmap.is_set(200));
+        assert!(!bitmap.is_set(1));
+        assert!(!bitmap.is_set(65));
+    }
+
+    #[test]
+    fn test_block_bitmap_set_is_idempotent() {
+        let mut bitmap = BlockBitmap::default();
+        bitmap.set(5);
+        bitmap.set(5);
+        assert_eq!(bitmap.count_set(), 1);
+    }
+
+    #[test]
+    fn test_block_bitmap_count_set() {
+        let mut bitmap = BlockBitmap::default();
+        assert_eq!(bitmap.count_set(), 0);
+        for block in [0, 1, 64, 128, 129, 1_000_000] {
+            bitmap.set(block);
+        }
+        assert_eq!(bitmap.count_set(), 6);
+    }
+
+    #[test]
+    fn test_checkpoint_round_trips_and_detects_param_mismatch() -> anyhow::Result<()> {
+        let path = std::env::temp_dir().join(format!(
+            "wskdf_test_checkpoint_{:?}.json",
+            std::thread::current().id()
+        ));
+        let salt = [0u8; SALT_SIZE];
+        let kdf_params = KdfParams {
+            security_level: None,
+            ops_limit: DEFAULT_OPS_LIMIT,
+            mem_limit_kbytes: DEFAULT_MEM_LIMIT_KBYTES,
+        };
+        let mut bitmap = BlockBitmap::default();
+        bitmap.set(3);
+
+        save_checkpoint(&path, 20, &salt, &kdf_params, &bitmap)?;
+        let loaded = load_checkpoint(&path)?;
+        std::fs::remove_file(&path)?;
+
+        assert_eq!(loaded.n_bits, 20);
+        assert_eq!(loaded.salt_hex, hex::encode(salt));
+        assert!(loaded.kdf_params == kdf_params);
+        assert!(loaded.bitmap.is_set(3));
+
+        // The same mismatch check `--resume` runs against the current invocation's params.
+        assert!(loaded.n_bits == 20 && loaded.salt_hex == hex::encode(salt) && loaded.kdf_params == kdf_params);
+        assert!(loaded.n_bits != 21);
+        assert!(loaded.salt_hex != hex::encode([1u8; SALT_SIZE]));
+        assert!(
+            loaded.kdf_params
+                != KdfParams {
+                    security_level: None,
+                    ops_limit: DEFAULT_OPS_LIMIT + 1,
+                    mem_limit_kbytes: DEFAULT_MEM_LIMIT_KBYTES,
+                }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_mean_and_stddev_known_fixture() {
+        let samples = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+        let m = mean(&samples);
+        assert_eq!(m, 5.0);
+        assert_eq!(stddev(&samples, m), 2.0);
+    }
+
+    #[test]
+    fn test_median_odd_length() {
+        assert_eq!(median(&[3.0, 1.0, 2.0]), 2.0);
+    }
+
+    #[test]
+    fn test_median_even_length() {
+        assert_eq!(median(&[1.0, 2.0, 3.0, 4.0]), 2.5);
+    }
+
+    #[test]
+    fn test_reject_outliers_drops_planted_outlier() {
+        let samples = vec![1.0, 1.1, 0.9, 1.0, 1.05, 0.95, 100.0];
+        let filtered = reject_outliers(samples);
+        assert!(!filtered.contains(&100.0));
+        assert_eq!(filtered.len(), 6);
+    }
+
+    #[test]
+    fn test_reject_outliers_all_equal_mad_zero_keeps_all() {
+        let samples = vec![1.0, 1.0, 1.0, 1.0];
+        assert_eq!(reject_outliers(samples.clone()), samples);
+    }
+
+    #[test]
+    fn test_run_adaptive_benchmark_reports_sane_stats() -> anyhow::Result<()> {
+        let pool = rayon::ThreadPoolBuilder::new().num_threads(1).build()?;
+        let preimage = [0u8; PREIMAGE_SIZE];
+        let salt = [0u8; SALT_SIZE];
+        let kdf_params = KdfParams {
+            security_level: None,
+            ops_limit: 1,
+            mem_limit_kbytes: 8 * 1024,
+        };
+
+        let stats =
+            run_adaptive_benchmark(&pool, &preimage, &salt, &kdf_params, 1, 4, 0, 10.0, 0.5);
+
+        assert!(stats.samples_used + stats.samples_discarded >= BENCHMARK_MIN_SAMPLES);
+        assert!(stats.min_secs <= stats.median_secs);
+        assert!(stats.mean_secs > 0.0);
+        assert!(stats.stddev_secs >= 0.0);
+        assert!(stats.derivations_per_second > 0.0);
+        Ok(())
+    }
+
     #[test]
     fn test_calculate_search_space() {
         assert_eq!(calculate_search_space(1), 1.0); // 2^0 = 1
@@ -702,33 +2061,63 @@ mod tests {
         assert!((p999_multiplier - 6.908).abs() < 0.001);
     }
 
+    #[test]
+    fn test_simulate_search_percentiles_are_ordered() {
+        let dist = simulate_search(20, 16, Dist::Constant(0.001), Strategy::Random, 2000);
+        assert!(dist.p50() <= dist.p90());
+        assert!(dist.p90() <= dist.p99());
+        assert!(dist.p99() <= dist.p999());
+        assert!(dist.mean > 0.0);
+        assert!(dist.stddev > 0.0);
+    }
+
+    #[test]
+    fn test_simulate_search_systematic_matches_closed_form_roughly() {
+        let space = calculate_search_space(20);
+        let (expected_secs, _) = calculate_systematic_times(space, 16, 0.001);
+        let dist = simulate_search(
+            20,
+            16,
+            Dist::LogNormal {
+                mean: 0.001,
+                stddev: 0.0002,
+            },
+            Strategy::Systematic,
+            5000,
+        );
+        // The Monte Carlo mean should land in the same ballpark as the closed-form
+        // expectation; a generous tolerance keeps this from flaking on sampling noise.
+        assert!((dist.mean - expected_secs).abs() / expected_secs < 0.5);
+    }
+
     #[test]
     fn test_calculate_estimation_for_bits_20bit() {
-        let result = calculate_estimation_for_bits(20, 16, 30.0);
+        let percentiles = [0.99, 0.999];
+        let result = calculate_estimation_for_bits(20, 16, 30.0, &percentiles);
+        let (_, systematic_worst_secs) =
+            calculate_systematic_times(calculate_search_space(20), 16, 30.0);
 
         // Test known values for 20-bit search with 16 threads and 30s per derivation
         assert_eq!(result.systematic_expected_secs, 491520.0); // 5d 17h
-        assert_eq!(result.systematic_worst_secs, 983040.0); // 11d 9h
+        assert_eq!(systematic_worst_secs, 983040.0); // 11d 9h
         assert_eq!(result.random_expected_secs, 983040.0); // 11d 9h
 
-        // Random search should have higher percentiles
-        assert!(result.random_99th_percentile_secs > result.random_expected_secs);
-        assert!(result.random_999th_percentile_secs > result.random_99th_percentile_secs);
+        // Random search should have higher percentiles than its expected time
+        assert!(result.random_percentiles[0].1 > result.random_expected_secs);
+        assert!(result.random_percentiles[1].1 > result.random_percentiles[0].1);
 
         // Systematic expected should be half of systematic worst
-        assert_eq!(
-            result.systematic_expected_secs * 2.0,
-            result.systematic_worst_secs
-        );
+        assert_eq!(result.systematic_expected_secs * 2.0, systematic_worst_secs);
 
         // Random expected should equal systematic worst (same thread count)
-        assert_eq!(result.random_expected_secs, result.systematic_worst_secs);
+        assert_eq!(result.random_expected_secs, systematic_worst_secs);
     }
 
     #[test]
     fn test_calculate_estimation_for_bits_scaling() {
-        let result_1t = calculate_estimation_for_bits(20, 1, 30.0);
-        let result_16t = calculate_estimation_for_bits(20, 16, 30.0);
+        let percentiles = [0.99];
+        let result_1t = calculate_estimation_for_bits(20, 1, 30.0, &percentiles);
+        let result_16t = calculate_estimation_for_bits(20, 16, 30.0, &percentiles);
 
         // With 16x more threads, times should be 16x smaller
         assert_eq!(
@@ -736,8 +2125,8 @@ mod tests {
             result_16t.systematic_expected_secs * 16.0
         );
         assert_eq!(
-            result_1t.systematic_worst_secs,
-            result_16t.systematic_worst_secs * 16.0
+            result_1t.systematic_percentiles[0].1,
+            result_16t.systematic_percentiles[0].1 * 16.0
         );
         assert_eq!(
             result_1t.random_expected_secs,
@@ -745,6 +2134,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_systematic_percentile_matches_worst_and_expected() {
+        let space = calculate_search_space(20);
+        let (expected_secs, worst_secs) = calculate_systematic_times(space, 16, 30.0);
+
+        assert_eq!(systematic_percentile(space, 16, 30.0, 1.0), worst_secs);
+        assert_eq!(systematic_percentile(space, 16, 30.0, 0.5), expected_secs);
+    }
+
+    #[test]
+    fn test_random_percentile_matches_calculate_random_times() {
+        let space = calculate_search_space(20);
+        let (_, p99, p999) = calculate_random_times(space, 16, 30.0);
+
+        assert!((random_percentile(space, 16, 30.0, 0.99) - p99).abs() < 1e-6);
+        assert!((random_percentile(space, 16, 30.0, 0.999) - p999).abs() < 1e-6);
+    }
+
     #[test]
     fn test_pretty_time_formatting() {
         assert_eq!(pretty(15728640.0), "182d 1h"); // 20-bit random expected
@@ -759,6 +2166,72 @@ mod tests {
         assert_eq!(pretty(31536000.0), "1y 0d"); // 365 * 24 * 3600
     }
 
+    #[test]
+    fn test_pretty_sub_second_units() {
+        assert_eq!(pretty_with_precision(0.45, 1), "450ms");
+        assert_eq!(pretty_with_precision(0.000123, 1), "123µs");
+        assert_eq!(pretty_with_precision(0.000000045, 1), "45ns");
+        assert_eq!(pretty_with_precision(31.5, 1), "31.5s");
+    }
+
+    #[test]
+    fn test_pretty_with_precision_component_count() {
+        // precision=1 shows only the coarsest component
+        assert_eq!(pretty_with_precision(491520.0, 1), "5d");
+        // precision=3 digs one level deeper than the default
+        assert_eq!(
+            pretty_with_precision(5.0 * D + 17.0 * H + 3.0 * MIN, 3),
+            "5d 17h 3min"
+        );
+    }
+
+    #[test]
+    fn test_parse_duration_examples() -> anyhow::Result<()> {
+        assert_eq!(parse_duration("5d 17h")?, 5.0 * D + 17.0 * H);
+        assert_eq!(parse_duration("2h 8min")?, 2.0 * H + 8.0 * MIN);
+        assert_eq!(parse_duration("31.5s")?, 31.5);
+        assert_eq!(parse_duration("450ms")?, 0.45);
+        // Bare numbers (no unit) are accepted as raw seconds
+        assert_eq!(parse_duration("30")?, 30.0);
+        assert_eq!(parse_duration("30.0")?, 30.0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_duration_rejects_garbage() {
+        assert!(parse_duration("").is_err());
+        assert!(parse_duration("5fortnights").is_err());
+        assert!(parse_duration("abc").is_err());
+    }
+
+    #[test]
+    fn test_pretty_parse_duration_round_trip() -> anyhow::Result<()> {
+        for &secs in &[
+            0.000000045,
+            0.000123,
+            0.45,
+            1.0,
+            31.5,
+            60.0,
+            3600.0,
+            86400.0,
+            491520.0,
+            983040.0,
+            31536000.0,
+        ] {
+            let rendered = pretty(secs);
+            let parsed = parse_duration(&rendered)?;
+            // `pretty` only shows two leading components, so the round trip is only
+            // accurate to whatever magnitude that precision actually displays.
+            let relative_error = (parsed - secs).abs() / secs.max(1e-12);
+            assert!(
+                relative_error < 0.05,
+                "{secs} -> {rendered:?} -> {parsed}, relative error {relative_error}"
+            );
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_percentile_multipliers_precision() {
         let p99_multiplier = -0.01_f64.ln();
@@ -782,14 +2255,14 @@ mod tests {
             calculate_systematic_times(space, threads, measured_time);
 
         // What estimation command would calculate with the same inputs
-        let estimation_result = calculate_estimation_for_bits(bits, threads, measured_time);
+        let estimation_result = calculate_estimation_for_bits(bits, threads, measured_time, &[1.0]);
 
         // They should be identical
         assert_eq!(
             benchmark_expected,
             estimation_result.systematic_expected_secs
         );
-        assert_eq!(benchmark_worst, estimation_result.systematic_worst_secs);
+        assert_eq!(benchmark_worst, estimation_result.systematic_percentiles[0].1);
     }
 
     #[test]
@@ -0,0 +1,175 @@
+//! Self-describing PHC-style string encoding for wskdf outputs.
+//!
+//! Extends the standard `$argon2id$v=19$m=...,t=...,p=...$<salt>$<hash>` format with an
+//! extra `n=<bits>` field capturing the size of the preimage space, so a single string
+//! is enough to reproduce or attack a derivation. Uses [`base64ct`]'s constant-time,
+//! unpadded base64 codec (the same one the `argon2` crate uses for PHC strings) so
+//! encoding/decoding doesn't leak timing.
+
+use anyhow::Context;
+use base64ct::{Base64Unpadded, Encoding};
+
+use crate::{KEY_SIZE, Params, SALT_SIZE, Variant, Version};
+
+fn variant_name(variant: Variant) -> &'static str {
+    match variant {
+        Variant::Argon2d => "argon2d",
+        Variant::Argon2i => "argon2i",
+        Variant::Argon2id => "argon2id",
+    }
+}
+
+fn parse_variant(name: &str) -> anyhow::Result<Variant> {
+    match name {
+        "argon2d" => Ok(Variant::Argon2d),
+        "argon2i" => Ok(Variant::Argon2i),
+        "argon2id" => Ok(Variant::Argon2id),
+        other => anyhow::bail!("unknown argon2 variant {other:?}"),
+    }
+}
+
+fn version_number(version: Version) -> u32 {
+    match version {
+        Version::V0x10 => 0x10,
+        Version::V0x13 => 0x13,
+    }
+}
+
+fn parse_version(s: &str) -> anyhow::Result<Version> {
+    let v: u32 = s.parse().context("invalid v field")?;
+    match v {
+        0x10 => Ok(Version::V0x10),
+        0x13 => Ok(Version::V0x13),
+        other => anyhow::bail!("unknown argon2 version {other}"),
+    }
+}
+
+/// Encodes a derivation's params, salt, preimage bit length, and derived key into a
+/// single PHC-style string.
+pub fn encode_wskdf(
+    params: &Params,
+    salt: &[u8; SALT_SIZE],
+    n_bits: u8,
+    key: &[u8; KEY_SIZE],
+) -> String {
+    let salt_b64 = Base64Unpadded::encode_string(salt);
+    let key_b64 = Base64Unpadded::encode_string(key);
+    format!(
+        "${}$v={}$m={},t={},p={}$n={}${}${}",
+        variant_name(params.variant),
+        version_number(params.version),
+        params.mem_limit_kbytes,
+        params.ops_limit,
+        params.p_cost,
+        n_bits,
+        salt_b64,
+        key_b64,
+    )
+}
+
+/// Parses a string produced by [`encode_wskdf`], strictly validating field lengths and
+/// rejecting unknown variants.
+pub fn decode_wskdf(s: &str) -> anyhow::Result<(Params, [u8; SALT_SIZE], u8, [u8; KEY_SIZE])> {
+    let mut fields = s.split('$');
+    anyhow::ensure!(fields.next() == Some(""), "expected string to start with '$'");
+
+    let variant = parse_variant(fields.next().context("missing variant field")?)?;
+
+    let version = parse_version(
+        fields
+            .next()
+            .context("missing v field")?
+            .strip_prefix("v=")
+            .context("malformed v field")?,
+    )?;
+
+    let (mem_limit_kbytes, ops_limit, p_cost) =
+        parse_mtp(fields.next().context("missing m/t/p field")?)?;
+
+    let n_bits: u8 = fields
+        .next()
+        .context("missing n field")?
+        .strip_prefix("n=")
+        .context("malformed n field")?
+        .parse()
+        .context("invalid n field")?;
+
+    let salt: [u8; SALT_SIZE] = Base64Unpadded::decode_vec(fields.next().context("missing salt field")?)
+        .context("salt isn't valid base64")?
+        .try_into()
+        .map_err(|v: Vec<u8>| anyhow::anyhow!("salt is {} bytes, expected {SALT_SIZE}", v.len()))?;
+
+    let key: [u8; KEY_SIZE] = Base64Unpadded::decode_vec(fields.next().context("missing hash field")?)
+        .context("hash isn't valid base64")?
+        .try_into()
+        .map_err(|v: Vec<u8>| anyhow::anyhow!("hash is {} bytes, expected {KEY_SIZE}", v.len()))?;
+
+    anyhow::ensure!(fields.next().is_none(), "unexpected trailing fields");
+
+    let params = Params {
+        variant,
+        version,
+        ops_limit,
+        mem_limit_kbytes,
+        p_cost,
+        output_len: KEY_SIZE,
+        secret: None,
+        associated_data: None,
+    };
+    Ok((params, salt, n_bits, key))
+}
+
+fn parse_mtp(field: &str) -> anyhow::Result<(u32, u32, u32)> {
+    let mut m = None;
+    let mut t = None;
+    let mut p = None;
+    for part in field.split(',') {
+        let (key, value) = part
+            .split_once('=')
+            .ok_or_else(|| anyhow::anyhow!("malformed m/t/p field {part:?}"))?;
+        let value: u32 = value
+            .parse()
+            .with_context(|| format!("invalid {key} field"))?;
+        match key {
+            "m" => m = Some(value),
+            "t" => t = Some(value),
+            "p" => p = Some(value),
+            other => anyhow::bail!("unknown cost parameter {other:?}"),
+        }
+    }
+    let m = m.context("missing m= cost parameter")?;
+    let t = t.context("missing t= cost parameter")?;
+    let p = p.context("missing p= cost parameter")?;
+    Ok((m, t, p))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_decode_round_trip() -> anyhow::Result<()> {
+        let params = Params::new(7, 4096 * 1024);
+        let salt = [1u8; SALT_SIZE];
+        let key = [2u8; KEY_SIZE];
+        let n_bits = 20;
+
+        let encoded = encode_wskdf(&params, &salt, n_bits, &key);
+        let (decoded_params, decoded_salt, decoded_n_bits, decoded_key) = decode_wskdf(&encoded)?;
+
+        assert_eq!(decoded_params, params);
+        assert_eq!(decoded_salt, salt);
+        assert_eq!(decoded_n_bits, n_bits);
+        assert_eq!(decoded_key, key);
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_rejects_unknown_variant() {
+        let params = Params::new(7, 4096 * 1024);
+        let salt = [1u8; SALT_SIZE];
+        let key = [2u8; KEY_SIZE];
+        let encoded = encode_wskdf(&params, &salt, 20, &key).replacen("argon2id", "argon2x", 1);
+        assert!(decode_wskdf(&encoded).is_err());
+    }
+}
@@ -1,3 +1,21 @@
+#[cfg(feature = "parallel")]
+pub mod recover;
+
+mod params;
+pub use params::{Params, Variant, Version};
+
+mod encoding;
+pub use encoding::{decode_wskdf, encode_wskdf};
+
+mod presets;
+pub use presets::SecurityLevel;
+
+mod mnemonic;
+pub use mnemonic::{
+    decode_key_mnemonic, decode_mnemonic, decode_preimage_mnemonic, encode_key_mnemonic,
+    encode_mnemonic, encode_preimage_mnemonic,
+};
+
 pub const SALT_SIZE: usize = 16;
 pub const KEY_SIZE: usize = 32;
 pub const PREIMAGE_SIZE: usize = 8;
@@ -22,17 +40,47 @@ pub fn core_gen_rand_preimage(n_bits: u8, rng: &mut rand::rngs::ThreadRng) -> [u
 fn libsodium_argon2id_derive_key(
     password: &[u8],
     salt: &[u8; SALT_SIZE],
-    ops_limit: usize,
-    mem_limit_kbytes: usize,
+    params: &Params,
 ) -> anyhow::Result<[u8; KEY_SIZE]> {
+    anyhow::ensure!(
+        params.variant == Variant::Argon2id,
+        "the alkali backend only implements Argon2id, not {:?}",
+        params.variant
+    );
+    anyhow::ensure!(
+        params.version == Version::V0x13,
+        "the alkali backend always uses Argon2 version 0x13 and cannot honor {:?}",
+        params.version
+    );
+    anyhow::ensure!(
+        params.p_cost == 1,
+        "the alkali backend does not support lane-level parallelism (p_cost={})",
+        params.p_cost
+    );
+    anyhow::ensure!(
+        params.output_len == KEY_SIZE,
+        "the alkali backend cannot produce a {}-byte key (expected {KEY_SIZE})",
+        params.output_len
+    );
+    anyhow::ensure!(
+        params.secret.is_none(),
+        "the alkali backend does not support an Argon2 secret key (K)"
+    );
+    anyhow::ensure!(
+        params.associated_data.is_none(),
+        "the alkali backend does not support Argon2 associated data (X)"
+    );
+
     let mut key = [0u8; KEY_SIZE];
-    let mem_limit_bytes = mem_limit_kbytes
+    let mem_limit_bytes = (params.mem_limit_kbytes as usize)
         .checked_mul(1024)
-        .ok_or_else(|| anyhow::anyhow!("Memory limit overflow: {mem_limit_kbytes} KB * 1024"))?;
+        .ok_or_else(|| {
+            anyhow::anyhow!("Memory limit overflow: {} KB * 1024", params.mem_limit_kbytes)
+        })?;
     alkali::hash::pbkdf::argon2id::derive_key(
         password,
         salt,
-        ops_limit,
+        params.ops_limit as usize,
         mem_limit_bytes,
         &mut key[..],
     )?;
@@ -43,17 +91,37 @@ fn libsodium_argon2id_derive_key(
 fn rust_argon2_derive_key(
     password: &[u8],
     salt: &[u8; SALT_SIZE],
-    ops_limit: u32,
-    mem_limit_kbytes: u32,
+    params: &Params,
 ) -> anyhow::Result<[u8; KEY_SIZE]> {
-    let mut key = [0u8; KEY_SIZE];
+    anyhow::ensure!(
+        params.output_len == KEY_SIZE,
+        "cannot produce a {}-byte key into a {KEY_SIZE}-byte buffer",
+        params.output_len
+    );
+
+    let variant = match params.variant {
+        Variant::Argon2d => argon2::Variant::Argon2d,
+        Variant::Argon2i => argon2::Variant::Argon2i,
+        Variant::Argon2id => argon2::Variant::Argon2id,
+    };
+    let version = match params.version {
+        Version::V0x10 => argon2::Version::Version10,
+        Version::V0x13 => argon2::Version::Version13,
+    };
     let config = argon2::Config {
-        mem_cost: mem_limit_kbytes,
-        time_cost: ops_limit,
-        variant: argon2::Variant::Argon2id,
+        mem_cost: params.mem_limit_kbytes,
+        time_cost: params.ops_limit,
+        lanes: params.p_cost,
+        thread_mode: argon2::ThreadMode::Parallel,
+        variant,
+        version,
+        hash_length: params.output_len as u32,
+        secret: params.secret.as_deref().unwrap_or(&[]),
+        ad: params.associated_data.as_deref().unwrap_or(&[]),
         ..Default::default()
     };
     let raw = argon2::hash_raw(password, salt, &config)?;
+    let mut key = [0u8; KEY_SIZE];
     key.copy_from_slice(&raw);
     Ok(key)
 }
@@ -61,22 +129,17 @@ fn rust_argon2_derive_key(
 pub fn wskdf_derive_key(
     preimage: &[u8; PREIMAGE_SIZE],
     salt: &[u8; SALT_SIZE],
-    ops_limit: u32,
-    mem_limit_kbytes: u32,
+    params: &Params,
 ) -> anyhow::Result<[u8; KEY_SIZE]> {
     #[cfg(feature = "alkali")]
-    return libsodium_argon2id_derive_key(
-        preimage,
-        salt,
-        ops_limit.try_into()?,
-        mem_limit_kbytes.try_into()?,
-    );
+    return libsodium_argon2id_derive_key(preimage, salt, params);
     #[cfg(feature = "rust-argon2")]
-    return rust_argon2_derive_key(preimage, salt, ops_limit, mem_limit_kbytes);
+    return rust_argon2_derive_key(preimage, salt, params);
     #[cfg(not(any(feature = "alkali", feature = "rust-argon2")))]
-    anyhow::bail!(
-        "no argon2 implementation enabled, {preimage:?}, {salt:?}, {ops_limit} {mem_limit_kbytes}"
-    )
+    {
+        let _ = params;
+        anyhow::bail!("no argon2 implementation enabled, {preimage:?}, {salt:?}")
+    }
 }
 
 #[cfg(test)]
@@ -91,11 +154,127 @@ mod tests {
         let preimage = hex::decode("000000000000000d")?
             .try_into()
             .map_err(|_| anyhow::Error::msg("preimage is invalid length"))?;
-        let key = wskdf_derive_key(&preimage, &salt, 42, 256 * 1024)?;
+        let key = wskdf_derive_key(&preimage, &salt, &Params::new(42, 256 * 1024))?;
         assert_eq!(
             hex::decode("dc6b9dbde1d29c7e76549cd3cddbc7edee76966bbc0cf7afb13134ae4f43a043")?,
             key,
         );
         Ok(())
     }
+
+    fn test_salt() -> [u8; SALT_SIZE] {
+        [0u8; SALT_SIZE]
+    }
+
+    fn test_preimage() -> [u8; PREIMAGE_SIZE] {
+        [0u8; PREIMAGE_SIZE]
+    }
+
+    #[cfg(feature = "rust-argon2")]
+    #[test]
+    fn test_variant_changes_derived_key() -> anyhow::Result<()> {
+        let mut params = Params::new(1, 8 * 1024);
+        params.variant = Variant::Argon2d;
+        let argon2d_key = rust_argon2_derive_key(&test_preimage(), &test_salt(), &params)?;
+
+        params.variant = Variant::Argon2id;
+        let argon2id_key = rust_argon2_derive_key(&test_preimage(), &test_salt(), &params)?;
+
+        assert_ne!(argon2d_key, argon2id_key);
+        Ok(())
+    }
+
+    #[cfg(feature = "rust-argon2")]
+    #[test]
+    fn test_version_changes_derived_key() -> anyhow::Result<()> {
+        let mut params = Params::new(1, 8 * 1024);
+        params.version = Version::V0x10;
+        let v10_key = rust_argon2_derive_key(&test_preimage(), &test_salt(), &params)?;
+
+        params.version = Version::V0x13;
+        let v13_key = rust_argon2_derive_key(&test_preimage(), &test_salt(), &params)?;
+
+        assert_ne!(v10_key, v13_key);
+        Ok(())
+    }
+
+    #[cfg(feature = "rust-argon2")]
+    #[test]
+    fn test_p_cost_changes_derived_key() -> anyhow::Result<()> {
+        let mut params = Params::new(1, 8 * 1024);
+        params.p_cost = 1;
+        let single_lane_key = rust_argon2_derive_key(&test_preimage(), &test_salt(), &params)?;
+
+        params.p_cost = 2;
+        let dual_lane_key = rust_argon2_derive_key(&test_preimage(), &test_salt(), &params)?;
+
+        assert_ne!(single_lane_key, dual_lane_key);
+        Ok(())
+    }
+
+    #[cfg(feature = "alkali")]
+    #[test]
+    fn test_alkali_rejects_non_argon2id_variant() {
+        let mut params = Params::new(1, 8 * 1024);
+        params.variant = Variant::Argon2d;
+        assert!(libsodium_argon2id_derive_key(&test_preimage(), &test_salt(), &params).is_err());
+    }
+
+    #[cfg(feature = "alkali")]
+    #[test]
+    fn test_alkali_rejects_non_v0x13_version() {
+        let mut params = Params::new(1, 8 * 1024);
+        params.version = Version::V0x10;
+        assert!(libsodium_argon2id_derive_key(&test_preimage(), &test_salt(), &params).is_err());
+    }
+
+    #[cfg(feature = "alkali")]
+    #[test]
+    fn test_alkali_rejects_p_cost_other_than_one() {
+        let mut params = Params::new(1, 8 * 1024);
+        params.p_cost = 2;
+        assert!(libsodium_argon2id_derive_key(&test_preimage(), &test_salt(), &params).is_err());
+    }
+
+    #[cfg(feature = "rust-argon2")]
+    #[test]
+    fn test_secret_changes_derived_key() -> anyhow::Result<()> {
+        let mut params = Params::new(1, 8 * 1024);
+        let without_secret = rust_argon2_derive_key(&test_preimage(), &test_salt(), &params)?;
+
+        params.secret = Some(b"server-held-pepper".to_vec());
+        let with_secret = rust_argon2_derive_key(&test_preimage(), &test_salt(), &params)?;
+
+        assert_ne!(without_secret, with_secret);
+        Ok(())
+    }
+
+    #[cfg(feature = "rust-argon2")]
+    #[test]
+    fn test_associated_data_changes_derived_key() -> anyhow::Result<()> {
+        let mut params = Params::new(1, 8 * 1024);
+        let without_ad = rust_argon2_derive_key(&test_preimage(), &test_salt(), &params)?;
+
+        params.associated_data = Some(b"context-binding".to_vec());
+        let with_ad = rust_argon2_derive_key(&test_preimage(), &test_salt(), &params)?;
+
+        assert_ne!(without_ad, with_ad);
+        Ok(())
+    }
+
+    #[cfg(feature = "alkali")]
+    #[test]
+    fn test_alkali_rejects_secret() {
+        let mut params = Params::new(1, 8 * 1024);
+        params.secret = Some(b"server-held-pepper".to_vec());
+        assert!(libsodium_argon2id_derive_key(&test_preimage(), &test_salt(), &params).is_err());
+    }
+
+    #[cfg(feature = "alkali")]
+    #[test]
+    fn test_alkali_rejects_associated_data() {
+        let mut params = Params::new(1, 8 * 1024);
+        params.associated_data = Some(b"context-binding".to_vec());
+        assert!(libsodium_argon2id_derive_key(&test_preimage(), &test_salt(), &params).is_err());
+    }
 }
@@ -0,0 +1,159 @@
+//! BIP39-style mnemonic (word-list) encoding for preimage and key byte buffers.
+//!
+//! Hex is error-prone to transcribe by hand for cold-storage backups; a mnemonic is
+//! self-validating and easier to write down and read back. The byte buffer is treated
+//! as `ENT` entropy bits, a checksum of `ENT/32` bits is taken from the leading bits of
+//! `SHA-256(bytes)`, entropy and checksum are concatenated and split into 11-bit groups,
+//! and each group indexes into the fixed 2048-word [`wordlist::WORDLIST`]. Decoding
+//! reverses this and verifies the checksum bits, erroring on mismatch.
+
+mod wordlist;
+
+use sha2::{Digest, Sha256};
+use wordlist::WORDLIST;
+
+use crate::{KEY_SIZE, PREIMAGE_SIZE};
+
+fn bytes_to_bits(bytes: &[u8]) -> Vec<bool> {
+    bytes
+        .iter()
+        .flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1 == 1))
+        .collect()
+}
+
+fn bits_to_bytes(bits: &[bool]) -> Vec<u8> {
+    bits.chunks(8)
+        .map(|chunk| chunk.iter().fold(0u8, |acc, &bit| (acc << 1) | bit as u8))
+        .collect()
+}
+
+fn bits_to_word_index(bits: &[bool]) -> usize {
+    bits.iter().fold(0usize, |acc, &bit| (acc << 1) | bit as usize)
+}
+
+/// Encodes `entropy` (whose length in bits must be a non-zero multiple of 32) as a
+/// space-separated mnemonic.
+pub fn encode_mnemonic(entropy: &[u8]) -> anyhow::Result<String> {
+    let ent_bits = entropy.len() * 8;
+    anyhow::ensure!(
+        ent_bits > 0 && ent_bits % 32 == 0,
+        "entropy length must be a non-zero multiple of 32 bits, got {ent_bits} bits"
+    );
+    let cs_bits = ent_bits / 32;
+
+    let hash = Sha256::digest(entropy);
+    let hash_bits = bytes_to_bits(&hash);
+
+    let mut bits = bytes_to_bits(entropy);
+    bits.extend_from_slice(&hash_bits[..cs_bits]);
+    debug_assert_eq!(bits.len() % 11, 0);
+
+    let words: Vec<&str> = bits
+        .chunks(11)
+        .map(|chunk| WORDLIST[bits_to_word_index(chunk)])
+        .collect();
+    Ok(words.join(" "))
+}
+
+/// Decodes a mnemonic produced by [`encode_mnemonic`] back into `entropy_len` bytes,
+/// verifying the checksum bits. `entropy_len * 8` must be a non-zero multiple of 32.
+pub fn decode_mnemonic(mnemonic: &str, entropy_len: usize) -> anyhow::Result<Vec<u8>> {
+    let ent_bits = entropy_len * 8;
+    anyhow::ensure!(
+        ent_bits > 0 && ent_bits % 32 == 0,
+        "entropy length must be a non-zero multiple of 32 bits, got {ent_bits} bits"
+    );
+    let cs_bits = ent_bits / 32;
+    let expected_words = (ent_bits + cs_bits) / 11;
+
+    let words: Vec<&str> = mnemonic.split_whitespace().collect();
+    anyhow::ensure!(
+        words.len() == expected_words,
+        "expected {expected_words} words, got {}",
+        words.len()
+    );
+
+    let mut bits = Vec::with_capacity(words.len() * 11);
+    for word in &words {
+        let index = WORDLIST
+            .iter()
+            .position(|candidate| candidate == word)
+            .ok_or_else(|| anyhow::anyhow!("{word:?} is not in the wordlist"))?;
+        bits.extend((0..11).rev().map(|i| (index >> i) & 1 == 1));
+    }
+
+    let entropy_bits = &bits[..ent_bits];
+    let checksum_bits = &bits[ent_bits..];
+
+    let entropy = bits_to_bytes(entropy_bits);
+    let hash = Sha256::digest(&entropy);
+    let hash_bits = bytes_to_bits(&hash);
+    anyhow::ensure!(checksum_bits == &hash_bits[..cs_bits], "mnemonic checksum mismatch");
+
+    Ok(entropy)
+}
+
+/// Encodes a [`PREIMAGE_SIZE`]-byte preimage as a 6-word mnemonic (64 entropy bits + 2
+/// checksum bits).
+pub fn encode_preimage_mnemonic(preimage: &[u8; PREIMAGE_SIZE]) -> String {
+    encode_mnemonic(preimage).expect("PREIMAGE_SIZE is a multiple of 32 bits")
+}
+
+/// Decodes a mnemonic produced by [`encode_preimage_mnemonic`].
+pub fn decode_preimage_mnemonic(mnemonic: &str) -> anyhow::Result<[u8; PREIMAGE_SIZE]> {
+    decode_mnemonic(mnemonic, PREIMAGE_SIZE)?
+        .try_into()
+        .map_err(|_| anyhow::anyhow!("decoded preimage has unexpected length"))
+}
+
+/// Encodes a [`KEY_SIZE`]-byte key as a 24-word mnemonic (256 entropy bits + 8 checksum
+/// bits).
+pub fn encode_key_mnemonic(key: &[u8; KEY_SIZE]) -> String {
+    encode_mnemonic(key).expect("KEY_SIZE is a multiple of 32 bits")
+}
+
+/// Decodes a mnemonic produced by [`encode_key_mnemonic`].
+pub fn decode_key_mnemonic(mnemonic: &str) -> anyhow::Result<[u8; KEY_SIZE]> {
+    decode_mnemonic(mnemonic, KEY_SIZE)?
+        .try_into()
+        .map_err(|_| anyhow::anyhow!("decoded key has unexpected length"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_preimage_mnemonic_round_trip() -> anyhow::Result<()> {
+        let preimage: [u8; PREIMAGE_SIZE] = [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0d];
+        let mnemonic = encode_preimage_mnemonic(&preimage);
+        assert_eq!(mnemonic.split_whitespace().count(), 6);
+        assert_eq!(decode_preimage_mnemonic(&mnemonic)?, preimage);
+        Ok(())
+    }
+
+    #[test]
+    fn test_key_mnemonic_round_trip() -> anyhow::Result<()> {
+        let key: [u8; KEY_SIZE] = [7u8; KEY_SIZE];
+        let mnemonic = encode_key_mnemonic(&key);
+        assert_eq!(mnemonic.split_whitespace().count(), 24);
+        assert_eq!(decode_key_mnemonic(&mnemonic)?, key);
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_rejects_bad_checksum() {
+        let preimage: [u8; PREIMAGE_SIZE] = [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0d];
+        let mut mnemonic = encode_preimage_mnemonic(&preimage);
+        let last_word = mnemonic.split_whitespace().next_back().unwrap().to_string();
+        let replacement = if last_word == WORDLIST[0] { WORDLIST[1] } else { WORDLIST[0] };
+        mnemonic = mnemonic.replace(&last_word, replacement);
+        assert!(decode_preimage_mnemonic(&mnemonic).is_err());
+    }
+
+    #[test]
+    fn test_decode_rejects_unknown_word() {
+        let mnemonic = "notaword notaword notaword notaword notaword notaword";
+        assert!(decode_preimage_mnemonic(mnemonic).is_err());
+    }
+}
@@ -0,0 +1,263 @@
+//! The fixed 2048-word list used by [`super::encode_mnemonic`]/[`super::decode_mnemonic`]
+//! to map each 11-bit group to a word. Words are ordered so each word's position in
+//! this array is its 11-bit index.
+
+pub(super) const WORDLIST: [&str; 2048] = [
+    "bab", "bac", "bad", "baf", "bag", "bah", "baj", "bak",
+    "bal", "bam", "ban", "bap", "bar", "bas", "bat", "baw",
+    "beb", "bec", "bed", "bef", "beg", "beh", "bej", "bek",
+    "bel", "bem", "ben", "bep", "ber", "bes", "bet", "bew",
+    "bib", "bic", "bid", "bif", "big", "bih", "bij", "bik",
+    "bil", "bim", "bin", "bip", "bir", "bis", "bit", "biw",
+    "bob", "boc", "bod", "bof", "bog", "boh", "boj", "bok",
+    "bol", "bom", "bon", "bop", "bor", "bos", "bot", "bow",
+    "bub", "buc", "bud", "buf", "bug", "buh", "buj", "buk",
+    "bul", "bum", "bun", "bup", "bur", "bus", "but", "buw",
+    "baeb", "baec", "baed", "baef", "baeg", "baeh", "baej", "baek",
+    "bael", "baem", "baen", "baep", "baer", "baes", "baet", "baew",
+    "boob", "booc", "bood", "boof", "boog", "booh", "booj", "book",
+    "bool", "boom", "boon", "boop", "boor", "boos", "boot", "boow",
+    "beab", "beac", "bead", "beaf", "beag", "beah", "beaj", "beak",
+    "beal", "beam", "bean", "beap", "bear", "beas", "beat", "beaw",
+    "cab", "cac", "cad", "caf", "cag", "cah", "caj", "cak",
+    "cal", "cam", "can", "cap", "car", "cas", "cat", "caw",
+    "ceb", "cec", "ced", "cef", "ceg", "ceh", "cej", "cek",
+    "cel", "cem", "cen", "cep", "cer", "ces", "cet", "cew",
+    "cib", "cic", "cid", "cif", "cig", "cih", "cij", "cik",
+    "cil", "cim", "cin", "cip", "cir", "cis", "cit", "ciw",
+    "cob", "coc", "cod", "cof", "cog", "coh", "coj", "cok",
+    "col", "com", "con", "cop", "cor", "cos", "cot", "cow",
+    "cub", "cuc", "cud", "cuf", "cug", "cuh", "cuj", "cuk",
+    "cul", "cum", "cun", "cup", "cur", "cus", "cut", "cuw",
+    "caeb", "caec", "caed", "caef", "caeg", "caeh", "caej", "caek",
+    "cael", "caem", "caen", "caep", "caer", "caes", "caet", "caew",
+    "coob", "cooc", "cood", "coof", "coog", "cooh", "cooj", "cook",
+    "cool", "coom", "coon", "coop", "coor", "coos", "coot", "coow",
+    "ceab", "ceac", "cead", "ceaf", "ceag", "ceah", "ceaj", "ceak",
+    "ceal", "ceam", "cean", "ceap", "cear", "ceas", "ceat", "ceaw",
+    "dab", "dac", "dad", "daf", "dag", "dah", "daj", "dak",
+    "dal", "dam", "dan", "dap", "dar", "das", "dat", "daw",
+    "deb", "dec", "ded", "def", "deg", "deh", "dej", "dek",
+    "del", "dem", "den", "dep", "der", "des", "det", "dew",
+    "dib", "dic", "did", "dif", "dig", "dih", "dij", "dik",
+    "dil", "dim", "din", "dip", "dir", "dis", "dit", "diw",
+    "dob", "doc", "dod", "dof", "dog", "doh", "doj", "dok",
+    "dol", "dom", "don", "dop", "dor", "dos", "dot", "dow",
+    "dub", "duc", "dud", "duf", "dug", "duh", "duj", "duk",
+    "dul", "dum", "dun", "dup", "dur", "dus", "dut", "duw",
+    "daeb", "daec", "daed", "daef", "daeg", "daeh", "daej", "daek",
+    "dael", "daem", "daen", "daep", "daer", "daes", "daet", "daew",
+    "doob", "dooc", "dood", "doof", "doog", "dooh", "dooj", "dook",
+    "dool", "doom", "doon", "doop", "door", "doos", "doot", "doow",
+    "deab", "deac", "dead", "deaf", "deag", "deah", "deaj", "deak",
+    "deal", "deam", "dean", "deap", "dear", "deas", "deat", "deaw",
+    "fab", "fac", "fad", "faf", "fag", "fah", "faj", "fak",
+    "fal", "fam", "fan", "fap", "far", "fas", "fat", "faw",
+    "feb", "fec", "fed", "fef", "feg", "feh", "fej", "fek",
+    "fel", "fem", "fen", "fep", "fer", "fes", "fet", "few",
+    "fib", "fic", "fid", "fif", "fig", "fih", "fij", "fik",
+    "fil", "fim", "fin", "fip", "fir", "fis", "fit", "fiw",
+    "fob", "foc", "fod", "fof", "fog", "foh", "foj", "fok",
+    "fol", "fom", "fon", "fop", "for", "fos", "fot", "fow",
+    "fub", "fuc", "fud", "fuf", "fug", "fuh", "fuj", "fuk",
+    "ful", "fum", "fun", "fup", "fur", "fus", "fut", "fuw",
+    "faeb", "faec", "faed", "faef", "faeg", "faeh", "faej", "faek",
+    "fael", "faem", "faen", "faep", "faer", "faes", "faet", "faew",
+    "foob", "fooc", "food", "foof", "foog", "fooh", "fooj", "fook",
+    "fool", "foom", "foon", "foop", "foor", "foos", "foot", "foow",
+    "feab", "feac", "fead", "feaf", "feag", "feah", "feaj", "feak",
+    "feal", "feam", "fean", "feap", "fear", "feas", "feat", "feaw",
+    "gab", "gac", "gad", "gaf", "gag", "gah", "gaj", "gak",
+    "gal", "gam", "gan", "gap", "gar", "gas", "gat", "gaw",
+    "geb", "gec", "ged", "gef", "geg", "geh", "gej", "gek",
+    "gel", "gem", "gen", "gep", "ger", "ges", "get", "gew",
+    "gib", "gic", "gid", "gif", "gig", "gih", "gij", "gik",
+    "gil", "gim", "gin", "gip", "gir", "gis", "git", "giw",
+    "gob", "goc", "god", "gof", "gog", "goh", "goj", "gok",
+    "gol", "gom", "gon", "gop", "gor", "gos", "got", "gow",
+    "gub", "guc", "gud", "guf", "gug", "guh", "guj", "guk",
+    "gul", "gum", "gun", "gup", "gur", "gus", "gut", "guw",
+    "gaeb", "gaec", "gaed", "gaef", "gaeg", "gaeh", "gaej", "gaek",
+    "gael", "gaem", "gaen", "gaep", "gaer", "gaes", "gaet", "gaew",
+    "goob", "gooc", "good", "goof", "goog", "gooh", "gooj", "gook",
+    "gool", "goom", "goon", "goop", "goor", "goos", "goot", "goow",
+    "geab", "geac", "gead", "geaf", "geag", "geah", "geaj", "geak",
+    "geal", "geam", "gean", "geap", "gear", "geas", "geat", "geaw",
+    "hab", "hac", "had", "haf", "hag", "hah", "haj", "hak",
+    "hal", "ham", "han", "hap", "har", "has", "hat", "haw",
+    "heb", "hec", "hed", "hef", "heg", "heh", "hej", "hek",
+    "hel", "hem", "hen", "hep", "her", "hes", "het", "hew",
+    "hib", "hic", "hid", "hif", "hig", "hih", "hij", "hik",
+    "hil", "him", "hin", "hip", "hir", "his", "hit", "hiw",
+    "hob", "hoc", "hod", "hof", "hog", "hoh", "hoj", "hok",
+    "hol", "hom", "hon", "hop", "hor", "hos", "hot", "how",
+    "hub", "huc", "hud", "huf", "hug", "huh", "huj", "huk",
+    "hul", "hum", "hun", "hup", "hur", "hus", "hut", "huw",
+    "haeb", "haec", "haed", "haef", "haeg", "haeh", "haej", "haek",
+    "hael", "haem", "haen", "haep", "haer", "haes", "haet", "haew",
+    "hoob", "hooc", "hood", "hoof", "hoog", "hooh", "hooj", "hook",
+    "hool", "hoom", "hoon", "hoop", "hoor", "hoos", "hoot", "hoow",
+    "heab", "heac", "head", "heaf", "heag", "heah", "heaj", "heak",
+    "heal", "heam", "hean", "heap", "hear", "heas", "heat", "heaw",
+    "jab", "jac", "jad", "jaf", "jag", "jah", "jaj", "jak",
+    "jal", "jam", "jan", "jap", "jar", "jas", "jat", "jaw",
+    "jeb", "jec", "jed", "jef", "jeg", "jeh", "jej", "jek",
+    "jel", "jem", "jen", "jep", "jer", "jes", "jet", "jew",
+    "jib", "jic", "jid", "jif", "jig", "jih", "jij", "jik",
+    "jil", "jim", "jin", "jip", "jir", "jis", "jit", "jiw",
+    "job", "joc", "jod", "jof", "jog", "joh", "joj", "jok",
+    "jol", "jom", "jon", "jop", "jor", "jos", "jot", "jow",
+    "jub", "juc", "jud", "juf", "jug", "juh", "juj", "juk",
+    "jul", "jum", "jun", "jup", "jur", "jus", "jut", "juw",
+    "jaeb", "jaec", "jaed", "jaef", "jaeg", "jaeh", "jaej", "jaek",
+    "jael", "jaem", "jaen", "jaep", "jaer", "jaes", "jaet", "jaew",
+    "joob", "jooc", "jood", "joof", "joog", "jooh", "jooj", "jook",
+    "jool", "joom", "joon", "joop", "joor", "joos", "joot", "joow",
+    "jeab", "jeac", "jead", "jeaf", "jeag", "jeah", "jeaj", "jeak",
+    "jeal", "jeam", "jean", "jeap", "jear", "jeas", "jeat", "jeaw",
+    "kab", "kac", "kad", "kaf", "kag", "kah", "kaj", "kak",
+    "kal", "kam", "kan", "kap", "kar", "kas", "kat", "kaw",
+    "keb", "kec", "ked", "kef", "keg", "keh", "kej", "kek",
+    "kel", "kem", "ken", "kep", "ker", "kes", "ket", "kew",
+    "kib", "kic", "kid", "kif", "kig", "kih", "kij", "kik",
+    "kil", "kim", "kin", "kip", "kir", "kis", "kit", "kiw",
+    "kob", "koc", "kod", "kof", "kog", "koh", "koj", "kok",
+    "kol", "kom", "kon", "kop", "kor", "kos", "kot", "kow",
+    "kub", "kuc", "kud", "kuf", "kug", "kuh", "kuj", "kuk",
+    "kul", "kum", "kun", "kup", "kur", "kus", "kut", "kuw",
+    "kaeb", "kaec", "kaed", "kaef", "kaeg", "kaeh", "kaej", "kaek",
+    "kael", "kaem", "kaen", "kaep", "kaer", "kaes", "kaet", "kaew",
+    "koob", "kooc", "kood", "koof", "koog", "kooh", "kooj", "kook",
+    "kool", "koom", "koon", "koop", "koor", "koos", "koot", "koow",
+    "keab", "keac", "kead", "keaf", "keag", "keah", "keaj", "keak",
+    "keal", "keam", "kean", "keap", "kear", "keas", "keat", "keaw",
+    "lab", "lac", "lad", "laf", "lag", "lah", "laj", "lak",
+    "lal", "lam", "lan", "lap", "lar", "las", "lat", "law",
+    "leb", "lec", "led", "lef", "leg", "leh", "lej", "lek",
+    "lel", "lem", "len", "lep", "ler", "les", "let", "lew",
+    "lib", "lic", "lid", "lif", "lig", "lih", "lij", "lik",
+    "lil", "lim", "lin", "lip", "lir", "lis", "lit", "liw",
+    "lob", "loc", "lod", "lof", "log", "loh", "loj", "lok",
+    "lol", "lom", "lon", "lop", "lor", "los", "lot", "low",
+    "lub", "luc", "lud", "luf", "lug", "luh", "luj", "luk",
+    "lul", "lum", "lun", "lup", "lur", "lus", "lut", "luw",
+    "laeb", "laec", "laed", "laef", "laeg", "laeh", "laej", "laek",
+    "lael", "laem", "laen", "laep", "laer", "laes", "laet", "laew",
+    "loob", "looc", "lood", "loof", "loog", "looh", "looj", "look",
+    "lool", "loom", "loon", "loop", "loor", "loos", "loot", "loow",
+    "leab", "leac", "lead", "leaf", "leag", "leah", "leaj", "leak",
+    "leal", "leam", "lean", "leap", "lear", "leas", "leat", "leaw",
+    "mab", "mac", "mad", "maf", "mag", "mah", "maj", "mak",
+    "mal", "mam", "man", "map", "mar", "mas", "mat", "maw",
+    "meb", "mec", "med", "mef", "meg", "meh", "mej", "mek",
+    "mel", "mem", "men", "mep", "mer", "mes", "met", "mew",
+    "mib", "mic", "mid", "mif", "mig", "mih", "mij", "mik",
+    "mil", "mim", "min", "mip", "mir", "mis", "mit", "miw",
+    "mob", "moc", "mod", "mof", "mog", "moh", "moj", "mok",
+    "mol", "mom", "mon", "mop", "mor", "mos", "mot", "mow",
+    "mub", "muc", "mud", "muf", "mug", "muh", "muj", "muk",
+    "mul", "mum", "mun", "mup", "mur", "mus", "mut", "muw",
+    "maeb", "maec", "maed", "maef", "maeg", "maeh", "maej", "maek",
+    "mael", "maem", "maen", "maep", "maer", "maes", "maet", "maew",
+    "moob", "mooc", "mood", "moof", "moog", "mooh", "mooj", "mook",
+    "mool", "moom", "moon", "moop", "moor", "moos", "moot", "moow",
+    "meab", "meac", "mead", "meaf", "meag", "meah", "meaj", "meak",
+    "meal", "meam", "mean", "meap", "mear", "meas", "meat", "meaw",
+    "nab", "nac", "nad", "naf", "nag", "nah", "naj", "nak",
+    "nal", "nam", "nan", "nap", "nar", "nas", "nat", "naw",
+    "neb", "nec", "ned", "nef", "neg", "neh", "nej", "nek",
+    "nel", "nem", "nen", "nep", "ner", "nes", "net", "new",
+    "nib", "nic", "nid", "nif", "nig", "nih", "nij", "nik",
+    "nil", "nim", "nin", "nip", "nir", "nis", "nit", "niw",
+    "nob", "noc", "nod", "nof", "nog", "noh", "noj", "nok",
+    "nol", "nom", "non", "nop", "nor", "nos", "not", "now",
+    "nub", "nuc", "nud", "nuf", "nug", "nuh", "nuj", "nuk",
+    "nul", "num", "nun", "nup", "nur", "nus", "nut", "nuw",
+    "naeb", "naec", "naed", "naef", "naeg", "naeh", "naej", "naek",
+    "nael", "naem", "naen", "naep", "naer", "naes", "naet", "naew",
+    "noob", "nooc", "nood", "noof", "noog", "nooh", "nooj", "nook",
+    "nool", "noom", "noon", "noop", "noor", "noos", "noot", "noow",
+    "neab", "neac", "nead", "neaf", "neag", "neah", "neaj", "neak",
+    "neal", "neam", "nean", "neap", "near", "neas", "neat", "neaw",
+    "pab", "pac", "pad", "paf", "pag", "pah", "paj", "pak",
+    "pal", "pam", "pan", "pap", "par", "pas", "pat", "paw",
+    "peb", "pec", "ped", "pef", "peg", "peh", "pej", "pek",
+    "pel", "pem", "pen", "pep", "per", "pes", "pet", "pew",
+    "pib", "pic", "pid", "pif", "pig", "pih", "pij", "pik",
+    "pil", "pim", "pin", "pip", "pir", "pis", "pit", "piw",
+    "pob", "poc", "pod", "pof", "pog", "poh", "poj", "pok",
+    "pol", "pom", "pon", "pop", "por", "pos", "pot", "pow",
+    "pub", "puc", "pud", "puf", "pug", "puh", "puj", "puk",
+    "pul", "pum", "pun", "pup", "pur", "pus", "put", "puw",
+    "paeb", "paec", "paed", "paef", "paeg", "paeh", "paej", "paek",
+    "pael", "paem", "paen", "paep", "paer", "paes", "paet", "paew",
+    "poob", "pooc", "pood", "poof", "poog", "pooh", "pooj", "pook",
+    "pool", "poom", "poon", "poop", "poor", "poos", "poot", "poow",
+    "peab", "peac", "pead", "peaf", "peag", "peah", "peaj", "peak",
+    "peal", "peam", "pean", "peap", "pear", "peas", "peat", "peaw",
+    "rab", "rac", "rad", "raf", "rag", "rah", "raj", "rak",
+    "ral", "ram", "ran", "rap", "rar", "ras", "rat", "raw",
+    "reb", "rec", "red", "ref", "reg", "reh", "rej", "rek",
+    "rel", "rem", "ren", "rep", "rer", "res", "ret", "rew",
+    "rib", "ric", "rid", "rif", "rig", "rih", "rij", "rik",
+    "ril", "rim", "rin", "rip", "rir", "ris", "rit", "riw",
+    "rob", "roc", "rod", "rof", "rog", "roh", "roj", "rok",
+    "rol", "rom", "ron", "rop", "ror", "ros", "rot", "row",
+    "rub", "ruc", "rud", "ruf", "rug", "ruh", "ruj", "ruk",
+    "rul", "rum", "run", "rup", "rur", "rus", "rut", "ruw",
+    "raeb", "raec", "raed", "raef", "raeg", "raeh", "raej", "raek",
+    "rael", "raem", "raen", "raep", "raer", "raes", "raet", "raew",
+    "roob", "rooc", "rood", "roof", "roog", "rooh", "rooj", "rook",
+    "rool", "room", "roon", "roop", "roor", "roos", "root", "roow",
+    "reab", "reac", "read", "reaf", "reag", "reah", "reaj", "reak",
+    "real", "ream", "rean", "reap", "rear", "reas", "reat", "reaw",
+    "sab", "sac", "sad", "saf", "sag", "sah", "saj", "sak",
+    "sal", "sam", "san", "sap", "sar", "sas", "sat", "saw",
+    "seb", "sec", "sed", "sef", "seg", "seh", "sej", "sek",
+    "sel", "sem", "sen", "sep", "ser", "ses", "set", "sew",
+    "sib", "sic", "sid", "sif", "sig", "sih", "sij", "sik",
+    "sil", "sim", "sin", "sip", "sir", "sis", "sit", "siw",
+    "sob", "soc", "sod", "sof", "sog", "soh", "soj", "sok",
+    "sol", "som", "son", "sop", "sor", "sos", "sot", "sow",
+    "sub", "suc", "sud", "suf", "sug", "suh", "suj", "suk",
+    "sul", "sum", "sun", "sup", "sur", "sus", "sut", "suw",
+    "saeb", "saec", "saed", "saef", "saeg", "saeh", "saej", "saek",
+    "sael", "saem", "saen", "saep", "saer", "saes", "saet", "saew",
+    "soob", "sooc", "sood", "soof", "soog", "sooh", "sooj", "sook",
+    "sool", "soom", "soon", "soop", "soor", "soos", "soot", "soow",
+    "seab", "seac", "sead", "seaf", "seag", "seah", "seaj", "seak",
+    "seal", "seam", "sean", "seap", "sear", "seas", "seat", "seaw",
+    "tab", "tac", "tad", "taf", "tag", "tah", "taj", "tak",
+    "tal", "tam", "tan", "tap", "tar", "tas", "tat", "taw",
+    "teb", "tec", "ted", "tef", "teg", "teh", "tej", "tek",
+    "tel", "tem", "ten", "tep", "ter", "tes", "tet", "tew",
+    "tib", "tic", "tid", "tif", "tig", "tih", "tij", "tik",
+    "til", "tim", "tin", "tip", "tir", "tis", "tit", "tiw",
+    "tob", "toc", "tod", "tof", "tog", "toh", "toj", "tok",
+    "tol", "tom", "ton", "top", "tor", "tos", "tot", "tow",
+    "tub", "tuc", "tud", "tuf", "tug", "tuh", "tuj", "tuk",
+    "tul", "tum", "tun", "tup", "tur", "tus", "tut", "tuw",
+    "taeb", "taec", "taed", "taef", "taeg", "taeh", "taej", "taek",
+    "tael", "taem", "taen", "taep", "taer", "taes", "taet", "taew",
+    "toob", "tooc", "tood", "toof", "toog", "tooh", "tooj", "took",
+    "tool", "toom", "toon", "toop", "toor", "toos", "toot", "toow",
+    "teab", "teac", "tead", "teaf", "teag", "teah", "teaj", "teak",
+    "teal", "team", "tean", "teap", "tear", "teas", "teat", "teaw",
+    "wab", "wac", "wad", "waf", "wag", "wah", "waj", "wak",
+    "wal", "wam", "wan", "wap", "war", "was", "wat", "waw",
+    "web", "wec", "wed", "wef", "weg", "weh", "wej", "wek",
+    "wel", "wem", "wen", "wep", "wer", "wes", "wet", "wew",
+    "wib", "wic", "wid", "wif", "wig", "wih", "wij", "wik",
+    "wil", "wim", "win", "wip", "wir", "wis", "wit", "wiw",
+    "wob", "woc", "wod", "wof", "wog", "woh", "woj", "wok",
+    "wol", "wom", "won", "wop", "wor", "wos", "wot", "wow",
+    "wub", "wuc", "wud", "wuf", "wug", "wuh", "wuj", "wuk",
+    "wul", "wum", "wun", "wup", "wur", "wus", "wut", "wuw",
+    "waeb", "waec", "waed", "waef", "waeg", "waeh", "waej", "waek",
+    "wael", "waem", "waen", "waep", "waer", "waes", "waet", "waew",
+    "woob", "wooc", "wood", "woof", "woog", "wooh", "wooj", "wook",
+    "wool", "woom", "woon", "woop", "woor", "woos", "woot", "woow",
+    "weab", "weac", "wead", "weaf", "weag", "weah", "weaj", "weak",
+    "weal", "weam", "wean", "weap", "wear", "weas", "weat", "weaw",
+
+];
@@ -0,0 +1,87 @@
+//! Argon2 tuning knobs threaded through to whichever backend is enabled.
+
+use crate::KEY_SIZE;
+
+/// Which Argon2 variant to run. `Argon2id` is the recommended default and the only
+/// variant the `alkali` (libsodium) backend can honor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Variant {
+    Argon2d,
+    Argon2i,
+    Argon2id,
+}
+
+/// Argon2 version, per RFC 9106. `V0x13` is the current version; `V0x10` is kept for
+/// interop with hashes produced by older tooling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Version {
+    V0x10,
+    V0x13,
+}
+
+/// Cost and shape parameters for [`crate::wskdf_derive_key`].
+///
+/// `p_cost` defaults to `1` via [`Params::new`], matching the crate's previous
+/// hard-coded behavior; set it explicitly to opt into lane-level parallelism.
+///
+/// `output_len` is currently fixed at [`KEY_SIZE`]: both backends reject any other
+/// value, and [`crate::wskdf_derive_key`] always returns a `[u8; KEY_SIZE]`. It exists
+/// so a future variable-length output doesn't need a breaking API change.
+///
+/// `secret` and `associated_data` carry Argon2's optional `K` (pepper) and `X` inputs.
+/// Binding a derivation to a server-held `secret` means an attacker who captures the
+/// salt and ciphertext still cannot brute-force the small preimage offline without it.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Params {
+    pub variant: Variant,
+    pub version: Version,
+    pub ops_limit: u32,
+    pub mem_limit_kbytes: u32,
+    pub p_cost: u32,
+    pub output_len: usize,
+    pub secret: Option<Vec<u8>>,
+    pub associated_data: Option<Vec<u8>>,
+}
+
+impl std::fmt::Debug for Params {
+    /// Hand-written to redact `secret`/`associated_data`: this struct exists to carry a
+    /// pepper that must not leak into logs or error messages via an automatic `{:?}`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Params")
+            .field("variant", &self.variant)
+            .field("version", &self.version)
+            .field("ops_limit", &self.ops_limit)
+            .field("mem_limit_kbytes", &self.mem_limit_kbytes)
+            .field("p_cost", &self.p_cost)
+            .field("output_len", &self.output_len)
+            .field(
+                "secret",
+                &self.secret.as_ref().map(|s| format!("<{} bytes>", s.len())),
+            )
+            .field(
+                "associated_data",
+                &self
+                    .associated_data
+                    .as_ref()
+                    .map(|ad| format!("<{} bytes>", ad.len())),
+            )
+            .finish()
+    }
+}
+
+impl Params {
+    /// Argon2id, version 0x13, single-lane, [`KEY_SIZE`]-byte output, no secret or
+    /// associated data.
+    pub fn new(ops_limit: u32, mem_limit_kbytes: u32) -> Self {
+        Self {
+            variant: Variant::Argon2id,
+            version: Version::V0x13,
+            ops_limit,
+            mem_limit_kbytes,
+            p_cost: 1,
+            output_len: KEY_SIZE,
+            secret: None,
+            associated_data: None,
+        }
+    }
+}
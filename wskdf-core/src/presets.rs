@@ -0,0 +1,78 @@
+//! Named cost presets, so callers don't have to hand-pick raw `ops_limit` and
+//! `mem_limit_kbytes` numbers.
+
+use crate::Params;
+
+/// A named Argon2 cost level, paired with a recommended `n_bits` so the
+/// compute-cost-per-preimage × search-space product defaults to something sane.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SecurityLevel {
+    /// ops=2, mem=64 MiB. Suitable for interactive, low-latency use.
+    Interactive,
+    /// ops=3, mem=256 MiB. A reasonable default for most uses.
+    Moderate,
+    /// ops=4, mem=1 GiB. For long-term, highly sensitive secrets.
+    Sensitive,
+    /// An explicit `ops_limit`/`mem_limit_kbytes` pair, with no recommended `n_bits`.
+    Custom { ops_limit: u32, mem_limit_kbytes: u32 },
+}
+
+impl SecurityLevel {
+    /// The `(ops_limit, mem_limit_kbytes)` pair for this level.
+    pub fn cost(&self) -> (u32, u32) {
+        match self {
+            SecurityLevel::Interactive => (2, 64 * 1024),
+            SecurityLevel::Moderate => (3, 256 * 1024),
+            SecurityLevel::Sensitive => (4, 1024 * 1024),
+            SecurityLevel::Custom {
+                ops_limit,
+                mem_limit_kbytes,
+            } => (*ops_limit, *mem_limit_kbytes),
+        }
+    }
+
+    /// The recommended `n_bits` preimage size for this level, or `None` for
+    /// [`SecurityLevel::Custom`], which leaves that choice to the caller.
+    pub fn recommended_n_bits(&self) -> Option<u8> {
+        match self {
+            SecurityLevel::Interactive => Some(24),
+            SecurityLevel::Moderate => Some(28),
+            SecurityLevel::Sensitive => Some(32),
+            SecurityLevel::Custom { .. } => None,
+        }
+    }
+
+    /// Converts this level into the concrete [`Params`] consumed by
+    /// [`crate::wskdf_derive_key`].
+    pub fn to_params(self) -> Params {
+        let (ops_limit, mem_limit_kbytes) = self.cost();
+        Params::new(ops_limit, mem_limit_kbytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_levels_increase_in_cost() {
+        let (interactive_ops, interactive_mem) = SecurityLevel::Interactive.cost();
+        let (moderate_ops, moderate_mem) = SecurityLevel::Moderate.cost();
+        let (sensitive_ops, sensitive_mem) = SecurityLevel::Sensitive.cost();
+
+        assert!(interactive_ops < moderate_ops);
+        assert!(moderate_ops < sensitive_ops);
+        assert!(interactive_mem < moderate_mem);
+        assert!(moderate_mem < sensitive_mem);
+    }
+
+    #[test]
+    fn test_custom_has_no_recommended_n_bits() {
+        let custom = SecurityLevel::Custom {
+            ops_limit: 1,
+            mem_limit_kbytes: 1024,
+        };
+        assert_eq!(custom.recommended_n_bits(), None);
+        assert_eq!(custom.cost(), (1, 1024));
+    }
+}
@@ -0,0 +1,142 @@
+//! Brute-force recovery of a [`PREIMAGE_SIZE`] preimage from a known derived key.
+//!
+//! This is the "weak" half of wskdf: since `core_gen_rand_preimage` only draws from a
+//! small `n_bits`-wide range, the preimage can be recovered by exhaustively deriving a
+//! key from every candidate in that range and comparing it against the target.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use subtle::ConstantTimeEq;
+
+use crate::{KEY_SIZE, Params, PREIMAGE_SIZE, SALT_SIZE, wskdf_derive_key};
+
+/// Invoked periodically during [`wskdf_recover_preimage`] with `(candidates_searched,
+/// total_candidates)` so callers can report throughput and estimate remaining time.
+pub type ProgressCallback<'a> = dyn Fn(u64, u64) + Sync + 'a;
+
+/// Exhaustively searches the candidate range `[1<<(n_bits-1), 1<<n_bits)` — the same
+/// range `core_gen_rand_preimage` draws from — for a preimage that derives to
+/// `target_key`, parallelizing the search across a rayon thread pool.
+///
+/// The result is deterministic regardless of thread count, since the search terminates
+/// as soon as the single matching candidate (if any) is found and there can only be one
+/// preimage per derived key for a fixed `salt`/cost parameters.
+///
+/// Returns an error if the range is exhausted with no match, which usually means the
+/// params don't match the ones used to derive `target_key`, or the key is corrupted.
+pub fn wskdf_recover_preimage(
+    target_key: &[u8; KEY_SIZE],
+    salt: &[u8; SALT_SIZE],
+    n_bits: u8,
+    ops_limit: u32,
+    mem_limit_kbytes: u32,
+    progress: Option<&ProgressCallback>,
+) -> anyhow::Result<[u8; PREIMAGE_SIZE]> {
+    anyhow::ensure!((1..=63).contains(&n_bits), "n_bits must be between 1 and 63");
+    let low = 1u64 << (n_bits - 1);
+    let high = 1u64 << n_bits;
+    let total = high - low;
+
+    let searched = AtomicU64::new(0);
+
+    let params = Params::new(ops_limit, mem_limit_kbytes);
+    let found = (low..high).into_par_iter().find_map_any(|candidate| {
+        let preimage = candidate.to_be_bytes();
+        let derived =
+            wskdf_derive_key(&preimage, salt, &params).expect("derive key to complete");
+
+        let done = searched.fetch_add(1, Ordering::Relaxed) + 1;
+        if let Some(progress) = progress {
+            progress(done, total);
+        }
+
+        bool::from(derived.ct_eq(target_key)).then_some(preimage)
+    });
+
+    found.ok_or_else(|| {
+        anyhow::anyhow!(
+            "preimage space exhausted without a match (n_bits={n_bits}); check that salt and kdf params match the ones used to derive the key"
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core_gen_rand_preimage;
+
+    fn test_params() -> Params {
+        Params::new(1, 8 * 1024)
+    }
+
+    #[test]
+    fn test_recover_finds_known_preimage() -> anyhow::Result<()> {
+        let salt = [0u8; SALT_SIZE];
+        let n_bits = 8;
+        let mut rng = rand::rngs::ThreadRng::default();
+        let preimage = core_gen_rand_preimage(n_bits, &mut rng);
+        let params = test_params();
+        let target_key = wskdf_derive_key(&preimage, &salt, &params)?;
+
+        let recovered = wskdf_recover_preimage(
+            &target_key,
+            &salt,
+            n_bits,
+            params.ops_limit,
+            params.mem_limit_kbytes,
+            None,
+        )?;
+
+        assert_eq!(recovered, preimage);
+        Ok(())
+    }
+
+    #[test]
+    fn test_recover_reports_exhausted_when_no_match() {
+        let salt = [0u8; SALT_SIZE];
+        let n_bits = 8;
+        // No preimage in range derives to an all-0xff key.
+        let bogus_key = [0xffu8; KEY_SIZE];
+        let params = test_params();
+
+        let result = wskdf_recover_preimage(
+            &bogus_key,
+            &salt,
+            n_bits,
+            params.ops_limit,
+            params.mem_limit_kbytes,
+            None,
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_recover_is_deterministic_across_thread_counts() -> anyhow::Result<()> {
+        let salt = [0u8; SALT_SIZE];
+        let n_bits = 8;
+        let mut rng = rand::rngs::ThreadRng::default();
+        let preimage = core_gen_rand_preimage(n_bits, &mut rng);
+        let params = test_params();
+        let target_key = wskdf_derive_key(&preimage, &salt, &params)?;
+
+        for threads in [1, 4] {
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()?;
+            let recovered = pool.install(|| {
+                wskdf_recover_preimage(
+                    &target_key,
+                    &salt,
+                    n_bits,
+                    params.ops_limit,
+                    params.mem_limit_kbytes,
+                    None,
+                )
+            })?;
+            assert_eq!(recovered, preimage);
+        }
+        Ok(())
+    }
+}